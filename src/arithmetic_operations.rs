@@ -1,8 +1,159 @@
+use crate::cell::{Cell, CellWidth, DoubleCell};
+use crate::errors::ForthError;
 use crate::stack::Stack;
+use std::str::FromStr;
 
-/// Aplica una operación aritmética sobre los dos elementos superiores de la pila.
+/// Comportamiento de las operaciones aritméticas cuando el resultado no entra en una celda.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Se conservan los bits menos significativos del resultado (aritmética modular), como
+    /// espera Forth.
+    #[default]
+    Wrap,
+    /// El resultado se limita al mayor o al menor valor representable.
+    Saturate,
+    /// La operación falla con `arithmetic-overflow`.
+    Error,
+}
+
+impl OverflowPolicy {
+    /// Ajusta el resultado exacto de una operación a una celda según la política.
+    ///
+    /// # Parámetros
+    /// - `result`: Resultado calculado sin desborde.
+    /// - `width`: Ancho de la celda donde se almacena el resultado.
+    ///
+    /// # Retorna
+    /// - `Ok(Cell)` con el valor a apilar.
+    /// - `Err(ForthError::ArithmeticOverflow)` si el resultado no entra en una celda y la
+    ///   política es `Error`.
+    pub fn apply(self, result: DoubleCell, width: CellWidth) -> Result<Cell, ForthError> {
+        match self {
+            _ if width.contains(result) => Ok(result as Cell),
+            OverflowPolicy::Wrap => Ok(width.wrap(result)),
+            OverflowPolicy::Saturate if result < 0 => Ok(width.min()),
+            OverflowPolicy::Saturate => Ok(width.max()),
+            OverflowPolicy::Error => Err(ForthError::ArithmeticOverflow),
+        }
+    }
+
+    /// Ajusta el resultado exacto sin signo de una operación a una celda según la política.
+    ///
+    /// # Parámetros
+    /// - `result`: Resultado sin signo calculado sin desborde.
+    /// - `width`: Ancho de la celda donde se almacena el resultado.
+    ///
+    /// # Retorna
+    /// - `Ok(Cell)` con los bits del valor a apilar.
+    /// - `Err(ForthError::ArithmeticOverflow)` si el resultado no entra en una celda y la
+    ///   política es `Error`.
+    pub fn apply_unsigned(self, result: u128, width: CellWidth) -> Result<Cell, ForthError> {
+        match self {
+            _ if result <= width.unsigned(-1) => Ok(width.wrap(result as DoubleCell)),
+            OverflowPolicy::Wrap => Ok(width.wrap(result as DoubleCell)),
+            OverflowPolicy::Saturate => Ok(-1),
+            OverflowPolicy::Error => Err(ForthError::ArithmeticOverflow),
+        }
+    }
+}
+
+impl FromStr for OverflowPolicy {
+    type Err = ForthError;
+
+    /// Obtiene la política a partir de su nombre: `wrap`, `saturate` o `error`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name.to_lowercase().as_str() {
+            "wrap" => Ok(OverflowPolicy::Wrap),
+            "saturate" => Ok(OverflowPolicy::Saturate),
+            "error" => Ok(OverflowPolicy::Error),
+            _ => Err(ForthError::InvalidArgument(name.to_string())),
+        }
+    }
+}
+
+/// Redondeo del cociente en las divisiones con signo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DivisionMode {
+    /// El cociente se redondea hacia cero y el resto tiene el signo del dividendo (`-7 2 /MOD`
+    /// deja `-1 -3`), como `SM/REM`.
+    #[default]
+    Symmetric,
+    /// El cociente se redondea hacia menos infinito y el resto tiene el signo del divisor
+    /// (`-7 2 /MOD` deja `1 -4`), como `FM/MOD`.
+    Floored,
+}
+
+impl DivisionMode {
+    /// Divide dos números según el modo de redondeo.
+    ///
+    /// # Parámetros
+    /// - `dividend`: Dividendo.
+    /// - `divisor`: Divisor, distinto de cero.
+    ///
+    /// # Retorna
+    /// El resto y el cociente, en ese orden (el orden en que se apilan).
+    pub fn divide(self, dividend: DoubleCell, divisor: DoubleCell) -> (DoubleCell, DoubleCell) {
+        let quotient = dividend / divisor;
+        let remainder = dividend % divisor;
+
+        match self {
+            DivisionMode::Floored if remainder != 0 && (remainder < 0) != (divisor < 0) => {
+                (remainder + divisor, quotient - 1)
+            }
+            _ => (remainder, quotient),
+        }
+    }
+}
+
+impl FromStr for DivisionMode {
+    type Err = ForthError;
+
+    /// Obtiene el modo a partir de su nombre: `symmetric` o `floored`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name.to_lowercase().as_str() {
+            "symmetric" => Ok(DivisionMode::Symmetric),
+            "floored" => Ok(DivisionMode::Floored),
+            _ => Err(ForthError::InvalidArgument(name.to_string())),
+        }
+    }
+}
+
+/// Operación aritmética primitiva.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArithmeticOp {
+    /// `+`
+    Add,
+    /// `-`
+    Subtract,
+    /// `*`
+    Multiply,
+    /// `/`
+    Divide,
+    /// `MOD`
+    Mod,
+    /// `/MOD`
+    DivMod,
+    /// `NEGATE`
+    Negate,
+    /// `ABS`
+    Abs,
+    /// `MIN`
+    Min,
+    /// `MAX`
+    Max,
+    /// `1+`
+    Increment,
+    /// `1-`
+    Decrement,
+    /// `2*`
+    Double,
+    /// `2/`
+    Half,
+}
+
+/// Aplica una operación aritmética sobre los elementos superiores de la pila.
 ///
-/// Esta función toma dos valores desde el tope de la pila, aplica la operación
+/// Esta función toma uno o dos valores desde el tope de la pila, aplica la operación
 /// aritmética especificada y coloca el resultado nuevamente en la pila.
 ///
 /// Las operaciones soportadas son:
@@ -10,26 +161,55 @@ use crate::stack::Stack;
 /// - `"-"`: Resta.
 /// - `"*"`: Multiplicación.
 /// - `"/"`: División.
+/// - `"MOD"`: Resto de la división.
+/// - `"/MOD"`: Resto y cociente de la división.
+/// - `"NEGATE"`: Cambio de signo.
+/// - `"ABS"`: Valor absoluto.
+/// - `"MIN"` y `"MAX"`: Menor y mayor de dos valores.
+/// - `"1+"` y `"1-"`: Incremento y decremento en uno.
+/// - `"2*"`: Multiplicación por dos.
+/// - `"2/"`: Desplazamiento aritmético de un bit a la derecha (división por dos redondeando
+///   hacia menos infinito, en cualquier modo de división).
+///
+/// Si el resultado no entra en una celda (por ejemplo `32767 1 +` o `-32768 -1 /`), se
+/// resuelve según la política de desborde indicada. Los cocientes y restos se redondean según
+/// el modo de división.
 ///
 /// # Parámetros
 ///
 /// - `stack`: Referencia mutable a la pila sobre la cual se realizará la operación.
-/// - `operator`: Un `&str` que indica el operador a aplicar (`+`, `-`, `*` o `/`).
+/// - `operator`: La operación a aplicar.
+/// - `overflow`: Política a aplicar cuando el resultado no entra en una celda.
+/// - `division`: Redondeo de las divisiones (`/`, `MOD`, `/MOD`).
 ///
 /// # Retorna
 ///
 /// - `Ok(())` si la operación se realiza correctamente.
-/// - `Err(String)` si:
+/// - `Err(ForthError)` si:
 ///   - La pila no contiene suficientes elementos (stack underflow).
 ///   - Se intenta una división por cero.
-///   - El operador no es reconocido.
-pub fn apply_arithmetic_operation(stack: &mut Stack, operator: &str) -> Result<(), String> {
+///   - El resultado desborda y la política es `OverflowPolicy::Error`.
+pub fn apply_arithmetic_operation(
+    stack: &mut Stack,
+    operator: ArithmeticOp,
+    overflow: OverflowPolicy,
+    division: DivisionMode,
+) -> Result<(), ForthError> {
     match operator {
-        "+" => sum(stack),
-        "-" => subtraction(stack),
-        "*" => multiplication(stack),
-        "/" => division(stack),
-        _ => Err("?".to_string()),
+        ArithmeticOp::Add => sum(stack, overflow),
+        ArithmeticOp::Subtract => subtraction(stack, overflow),
+        ArithmeticOp::Multiply => multiplication(stack, overflow),
+        ArithmeticOp::Divide => divide(stack, overflow, division, DivisionResult::Quotient),
+        ArithmeticOp::Mod => divide(stack, overflow, division, DivisionResult::Remainder),
+        ArithmeticOp::DivMod => divide(stack, overflow, division, DivisionResult::Both),
+        ArithmeticOp::Negate => unary(stack, overflow, |n| -n),
+        ArithmeticOp::Abs => unary(stack, overflow, DoubleCell::abs),
+        ArithmeticOp::Increment => unary(stack, overflow, |n| n + 1),
+        ArithmeticOp::Decrement => unary(stack, overflow, |n| n - 1),
+        ArithmeticOp::Double => unary(stack, overflow, |n| n * 2),
+        ArithmeticOp::Half => unary(stack, overflow, |n| n >> 1),
+        ArithmeticOp::Min => min_max(stack, Cell::min),
+        ArithmeticOp::Max => min_max(stack, Cell::max),
     }
 }
 
@@ -45,13 +225,13 @@ pub fn apply_arithmetic_operation(stack: &mut Stack, operator: &str) -> Result<(
 ///
 /// # Retorna
 ///
-/// - `Ok((i16, i16))` con los dos valores extraídos si la pila tiene al menos dos elementos.
-/// - `Err(String)` con el mensaje `"stack-underflow"` si la pila no tiene suficientes elementos.
-fn pop_operands(stack: &mut Stack) -> Result<(i16, i16), String> {
+/// - `Ok((Cell, Cell))` con los dos valores extraídos si la pila tiene al menos dos elementos.
+/// - `Err(ForthError)` con el mensaje `"stack-underflow"` si la pila no tiene suficientes elementos.
+fn pop_operands(stack: &mut Stack) -> Result<(Cell, Cell), ForthError> {
     if let (Some(a), Some(b)) = (stack.pop(), stack.pop()) {
         Ok((a, b))
     } else {
-        Err("stack-underflow".to_string())
+        Err(ForthError::StackUnderflow)
     }
 }
 
@@ -63,15 +243,17 @@ fn pop_operands(stack: &mut Stack) -> Result<(i16, i16), String> {
 /// # Parámetros
 ///
 /// - `stack`: Referencia mutable a la pila sobre la que se ejecuta la operación.
+/// - `overflow`: Política a aplicar cuando el resultado no entra en una celda.
 ///
 /// # Retorna
 ///
 /// - `Ok(())` si la operación se realiza correctamente.
-/// - `Err(String)` con el mensaje `"stack-underflow"` si la pila no tiene suficientes elementos.
-fn sum(stack: &mut Stack) -> Result<(), String> {
+/// - `Err(ForthError)` con el mensaje `"stack-underflow"` si la pila no tiene suficientes elementos,
+///   o `"stack-overflow"` si no hay lugar para el resultado.
+fn sum(stack: &mut Stack, overflow: OverflowPolicy) -> Result<(), ForthError> {
     let (a, b) = pop_operands(stack)?;
-    stack.push(b + a);
-    Ok(())
+    let result = DoubleCell::from(b) + DoubleCell::from(a);
+    stack.push(overflow.apply(result, stack.width())?)
 }
 
 /// Realiza la resta de los dos elementos superiores de la pila.
@@ -83,15 +265,17 @@ fn sum(stack: &mut Stack) -> Result<(), String> {
 /// # Parámetros
 ///
 /// - `stack`: Referencia mutable a la pila sobre la que se ejecuta la operación.
+/// - `overflow`: Política a aplicar cuando el resultado no entra en una celda.
 ///
 /// # Retorna
 ///
 /// - `Ok(())` si la operación se realiza correctamente.
-/// - `Err(String)` con el mensaje `"stack-underflow"` si la pila no tiene suficientes elementos.
-fn subtraction(stack: &mut Stack) -> Result<(), String> {
+/// - `Err(ForthError)` con el mensaje `"stack-underflow"` si la pila no tiene suficientes elementos,
+///   o `"stack-overflow"` si no hay lugar para el resultado.
+fn subtraction(stack: &mut Stack, overflow: OverflowPolicy) -> Result<(), ForthError> {
     let (a, b) = pop_operands(stack)?;
-    stack.push(b - a);
-    Ok(())
+    let result = DoubleCell::from(b) - DoubleCell::from(a);
+    stack.push(overflow.apply(result, stack.width())?)
 }
 
 /// Realiza la multiplicación de los dos elementos superiores de la pila.
@@ -103,37 +287,111 @@ fn subtraction(stack: &mut Stack) -> Result<(), String> {
 /// # Parámetros
 ///
 /// - `stack`: Referencia mutable a la pila sobre la que se ejecuta la operación.
+/// - `overflow`: Política a aplicar cuando el resultado no entra en una celda.
 ///
 /// # Retorna
 ///
 /// - `Ok(())` si la operación se realiza correctamente.
-/// - `Err(String)` con el mensaje `"stack-underflow"` si la pila no tiene suficientes elementos.
-fn multiplication(stack: &mut Stack) -> Result<(), String> {
+/// - `Err(ForthError)` con el mensaje `"stack-underflow"` si la pila no tiene suficientes elementos,
+///   o `"stack-overflow"` si no hay lugar para el resultado.
+fn multiplication(stack: &mut Stack, overflow: OverflowPolicy) -> Result<(), ForthError> {
     let (a, b) = pop_operands(stack)?;
-    stack.push(b * a);
-    Ok(())
+    let result = DoubleCell::from(b) * DoubleCell::from(a);
+    stack.push(overflow.apply(result, stack.width())?)
+}
+
+/// Resultados de una división que se apilan.
+#[derive(Clone, Copy)]
+enum DivisionResult {
+    /// Solo el cociente (`/`).
+    Quotient,
+    /// Solo el resto (`MOD`).
+    Remainder,
+    /// El resto y el cociente (`/MOD`).
+    Both,
 }
 
 /// Realiza la división de los dos elementos superiores de la pila.
 ///
 /// Extrae los dos valores del tope de la pila, realiza la operación `b / a`
-/// (donde `a` es el valor en el tope de la pila) y coloca el resultado en la pila.
+/// (donde `a` es el valor en el tope de la pila) y coloca en la pila el cociente, el resto, o
+/// ambos (el resto debajo del cociente).
 ///
 /// # Parámetros
 ///
 /// - `stack`: Referencia mutable a la pila sobre la que se ejecuta la operación.
+/// - `overflow`: Política a aplicar cuando el cociente no entra en una celda.
+/// - `division`: Redondeo del cociente y signo del resto.
+/// - `result`: Resultados a apilar.
 ///
 /// # Retorna
 ///
 /// - `Ok(())` si la operación se realiza correctamente.
-/// - `Err(String)` con:
+/// - `Err(ForthError)` con:
 ///   - `"stack-underflow"` si la pila no tiene suficientes elementos.
 ///   - `"division-by-zero"` si el divisor (`a`) es cero.
-fn division(stack: &mut Stack) -> Result<(), String> {
+///   - `"arithmetic-overflow"` si el cociente desborda y la política es `OverflowPolicy::Error`.
+fn divide(
+    stack: &mut Stack,
+    overflow: OverflowPolicy,
+    division: DivisionMode,
+    result: DivisionResult,
+) -> Result<(), ForthError> {
     let (a, b) = pop_operands(stack)?;
     if a == 0 {
-        return Err("division-by-zero".to_string());
+        return Err(ForthError::DivisionByZero);
+    }
+
+    let (remainder, quotient) = division.divide(DoubleCell::from(b), DoubleCell::from(a));
+    let width = stack.width();
+
+    match result {
+        DivisionResult::Quotient => stack.push(overflow.apply(quotient, width)?),
+        DivisionResult::Remainder => stack.push(remainder as Cell),
+        DivisionResult::Both => {
+            let quotient = overflow.apply(quotient, width)?;
+            stack.push(remainder as Cell)?;
+            stack.push(quotient)
+        }
     }
-    stack.push(b / a);
-    Ok(())
+}
+
+/// Reemplaza el tope de la pila por el resultado de aplicarle una operación.
+///
+/// # Parámetros
+///
+/// - `stack`: Referencia mutable a la pila sobre la que se ejecuta la operación.
+/// - `overflow`: Política a aplicar cuando el resultado no entra en una celda.
+/// - `operation`: Operación a aplicar, calculada sin desborde.
+///
+/// # Retorna
+///
+/// - `Ok(())` si la operación se realiza correctamente.
+/// - `Err(ForthError)` con:
+///   - `"stack-underflow"` si la pila está vacía.
+///   - `"arithmetic-overflow"` si el resultado desborda y la política es `OverflowPolicy::Error`.
+fn unary(
+    stack: &mut Stack,
+    overflow: OverflowPolicy,
+    operation: fn(DoubleCell) -> DoubleCell,
+) -> Result<(), ForthError> {
+    let value = stack.pop().ok_or(ForthError::StackUnderflow)?;
+    let result = operation(DoubleCell::from(value));
+    stack.push(overflow.apply(result, stack.width())?)
+}
+
+/// Reemplaza los dos elementos superiores de la pila por el menor o el mayor de ellos.
+///
+/// # Parámetros
+///
+/// - `stack`: Referencia mutable a la pila sobre la que se ejecuta la operación.
+/// - `select`: Función que elige uno de los dos valores.
+///
+/// # Retorna
+///
+/// - `Ok(())` si la operación se realiza correctamente.
+/// - `Err(ForthError)` con el mensaje `"stack-underflow"` si la pila no tiene suficientes elementos.
+fn min_max(stack: &mut Stack, select: fn(Cell, Cell) -> Cell) -> Result<(), ForthError> {
+    let (a, b) = pop_operands(stack)?;
+    stack.push(select(b, a))
 }
@@ -1,42 +1,136 @@
+use crate::cell::{Cell, DoubleCell};
+use crate::errors::ForthError;
 use crate::stack::Stack;
+use std::str::FromStr;
 
 /// Representación de valores booleanos en Forth.
-const FALSE: i16 = 0;
-const TRUE: i16 = -1;
+const FALSE: Cell = 0;
+const TRUE: Cell = -1;
+
+/// Comportamiento de `AND` y `OR`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogicMode {
+    /// Cualquier valor distinto de cero es verdadero y el resultado es siempre `TRUE` (-1) o
+    /// `FALSE` (0): `1 2 AND` deja `-1`.
+    #[default]
+    Logical,
+    /// Se opera bit a bit, como indica el estándar: `1 2 AND` deja `0`.
+    Bitwise,
+}
+
+impl FromStr for LogicMode {
+    type Err = ForthError;
+
+    /// Obtiene el modo a partir de su nombre: `logical` o `bitwise`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name.to_lowercase().as_str() {
+            "logical" => Ok(LogicMode::Logical),
+            "bitwise" => Ok(LogicMode::Bitwise),
+            _ => Err(ForthError::InvalidArgument(name.to_string())),
+        }
+    }
+}
+
+/// Convierte una condición en el valor booleano de Forth.
+fn flag(condition: bool) -> Cell {
+    if condition { TRUE } else { FALSE }
+}
+
+/// Operación booleana primitiva.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BooleanOp {
+    /// `=`
+    Equal,
+    /// `<>`
+    NotEqual,
+    /// `<`
+    Less,
+    /// `>`
+    Greater,
+    /// `U<`
+    UnsignedLess,
+    /// `U>`
+    UnsignedGreater,
+    /// `0=`
+    ZeroEqual,
+    /// `0<`
+    ZeroLess,
+    /// `0>`
+    ZeroGreater,
+    /// `WITHIN`
+    Within,
+    /// `AND`
+    And,
+    /// `OR`
+    Or,
+    /// `XOR`
+    Xor,
+    /// `INVERT`
+    Invert,
+    /// `LSHIFT`
+    LeftShift,
+    /// `RSHIFT`
+    RightShift,
+    /// `NOT`
+    Not,
+}
 
 /// Aplica una operación booleana sobre los elementos superiores de la pila.
 ///
-/// Esta función toma uno o dos valores desde el tope de la pila, dependiendo del operador,
-/// aplica la operación booleana especificada y coloca el resultado nuevamente en la pila.
+/// Esta función toma uno, dos o tres valores desde el tope de la pila, dependiendo del
+/// operador, aplica la operación booleana especificada y coloca el resultado nuevamente en la pila.
 ///
 /// Las operaciones soportadas son:
 /// - `"="`: Compara igualdad entre dos elementos.
+/// - `"<>"`: Compara desigualdad entre dos elementos.
 /// - `"<"`: Evalúa si el penúltimo valor es menor que el último.
 /// - `">"`: Evalúa si el penúltimo valor es mayor que el último.
-/// - `"AND"`: Conjunción lógica entre los dos valores superiores.
-/// - `"OR"`: Disyunción lógica entre los dos valores superiores.
+/// - `"U<"` y `"U>"`: Como `<` y `>`, interpretando los valores sin signo.
+/// - `"0="`, `"0<"` y `"0>"`: Comparan el valor superior con cero.
+/// - `"WITHIN"`: Evalúa si `lo <= n < hi` para `( n lo hi -- flag )`, dando la vuelta si `hi < lo`.
+/// - `"AND"`: Conjunción entre los dos valores superiores, lógica o bit a bit según `logic`.
+/// - `"OR"`: Disyunción entre los dos valores superiores, lógica o bit a bit según `logic`.
+/// - `"XOR"`: Disyunción exclusiva bit a bit.
+/// - `"INVERT"`: Invierte todos los bits del valor superior.
+/// - `"LSHIFT"` y `"RSHIFT"`: Desplazamiento lógico de bits `( x u -- x' )`.
 /// - `"NOT"`: Negación lógica del valor superior.
 ///
 /// # Parámetros
 ///
 /// - `stack`: Referencia mutable a la pila sobre la cual se realizará la operación.
-/// - `operator`: Un `&str` que representa el operador lógico a aplicar.
+/// - `operator`: La operación lógica a aplicar.
+/// - `logic`: Comportamiento de `AND` y `OR`.
 ///
 /// # Retorna
 ///
 /// - `Ok(())` si la operación se ejecuta correctamente.
-/// - `Err(String)` si:
+/// - `Err(ForthError)` si:
 ///   - La pila no contiene suficientes elementos (stack underflow).
-///   - El operador no es reconocido.
-pub fn apply_boolean_operation(stack: &mut Stack, operator: &str) -> Result<(), String> {
+pub fn apply_boolean_operation(
+    stack: &mut Stack,
+    operator: BooleanOp,
+    logic: LogicMode,
+) -> Result<(), ForthError> {
     match operator {
-        "=" => equal(stack),
-        "<" => lower_than(stack),
-        ">" => greater_than(stack),
-        "AND" => and(stack),
-        "OR" => or(stack),
-        "NOT" => not(stack),
-        _ => Err("?".to_string()),
+        BooleanOp::Equal => equal(stack),
+        BooleanOp::NotEqual => compare(stack, |a, b| b != a),
+        BooleanOp::Less => lower_than(stack),
+        BooleanOp::Greater => greater_than(stack),
+        BooleanOp::UnsignedLess => unsigned_compare(stack, |a, b| b < a),
+        BooleanOp::UnsignedGreater => unsigned_compare(stack, |a, b| b > a),
+        BooleanOp::ZeroEqual => compare_with_zero(stack, |n| n == 0),
+        BooleanOp::ZeroLess => compare_with_zero(stack, |n| n < 0),
+        BooleanOp::ZeroGreater => compare_with_zero(stack, |n| n > 0),
+        BooleanOp::Within => within(stack),
+        BooleanOp::And if logic == LogicMode::Bitwise => bitwise(stack, |a, b| b & a),
+        BooleanOp::Or if logic == LogicMode::Bitwise => bitwise(stack, |a, b| b | a),
+        BooleanOp::And => and(stack),
+        BooleanOp::Or => or(stack),
+        BooleanOp::Xor => bitwise(stack, |a, b| b ^ a),
+        BooleanOp::Invert => invert(stack),
+        BooleanOp::LeftShift => shift(stack, false),
+        BooleanOp::RightShift => shift(stack, true),
+        BooleanOp::Not => not(stack),
     }
 }
 
@@ -53,13 +147,13 @@ pub fn apply_boolean_operation(stack: &mut Stack, operator: &str) -> Result<(),
 ///
 /// # Retorna
 ///
-/// - `Ok((i16, i16))` si se logran extraer dos valores correctamente.
-/// - `Err(String)` con el mensaje `"stack-underflow"` si la pila no contiene suficientes elementos.
-fn pop_two_operands(stack: &mut Stack) -> Result<(i16, i16), String> {
+/// - `Ok((Cell, Cell))` si se logran extraer dos valores correctamente.
+/// - `Err(ForthError)` con el mensaje `"stack-underflow"` si la pila no contiene suficientes elementos.
+fn pop_two_operands(stack: &mut Stack) -> Result<(Cell, Cell), ForthError> {
     if let (Some(a), Some(b)) = (stack.pop(), stack.pop()) {
         Ok((a, b))
     } else {
-        Err("stack-underflow".to_string())
+        Err(ForthError::StackUnderflow)
     }
 }
 
@@ -75,11 +169,10 @@ fn pop_two_operands(stack: &mut Stack) -> Result<(i16, i16), String> {
 /// # Retorna
 ///
 /// - `Ok(())` si la operación se realiza correctamente.
-/// - `Err(String)` con el mensaje `"stack-underflow"` si la pila no contiene suficientes elementos.
-fn equal(stack: &mut Stack) -> Result<(), String> {
+/// - `Err(ForthError)` con el mensaje `"stack-underflow"` si la pila no contiene suficientes elementos.
+fn equal(stack: &mut Stack) -> Result<(), ForthError> {
     let (a, b) = pop_two_operands(stack)?;
-    stack.push(if a == b { TRUE } else { FALSE });
-    Ok(())
+    stack.push(if a == b { TRUE } else { FALSE })
 }
 
 /// Compara si el segundo valor desde el tope de la pila es menor que el primero.
@@ -95,11 +188,10 @@ fn equal(stack: &mut Stack) -> Result<(), String> {
 /// # Retorna
 ///
 /// - `Ok(())` si la operación se realiza correctamente.
-/// - `Err(String)` con el mensaje `"stack-underflow"` si la pila no contiene suficientes elementos.
-fn lower_than(stack: &mut Stack) -> Result<(), String> {
+/// - `Err(ForthError)` con el mensaje `"stack-underflow"` si la pila no contiene suficientes elementos.
+fn lower_than(stack: &mut Stack) -> Result<(), ForthError> {
     let (a, b) = pop_two_operands(stack)?;
-    stack.push(if b < a { TRUE } else { FALSE });
-    Ok(())
+    stack.push(if b < a { TRUE } else { FALSE })
 }
 
 /// Compara si el segundo valor desde el tope de la pila es mayor que el primero.
@@ -115,11 +207,10 @@ fn lower_than(stack: &mut Stack) -> Result<(), String> {
 /// # Retorna
 ///
 /// - `Ok(())` si la operación se realiza correctamente.
-/// - `Err(String)` con el mensaje `"stack-underflow"` si la pila no contiene suficientes elementos.
-fn greater_than(stack: &mut Stack) -> Result<(), String> {
+/// - `Err(ForthError)` con el mensaje `"stack-underflow"` si la pila no contiene suficientes elementos.
+fn greater_than(stack: &mut Stack) -> Result<(), ForthError> {
     let (a, b) = pop_two_operands(stack)?;
-    stack.push(if b > a { TRUE } else { FALSE });
-    Ok(())
+    stack.push(if b > a { TRUE } else { FALSE })
 }
 
 /// Realiza la conjunción lógica (AND) entre los dos valores superiores de la pila.
@@ -135,15 +226,14 @@ fn greater_than(stack: &mut Stack) -> Result<(), String> {
 /// # Retorna
 ///
 /// - `Ok(())` si la operación se realiza correctamente.
-/// - `Err(String)` con el mensaje `"stack-underflow"` si la pila no contiene suficientes elementos.
-fn and(stack: &mut Stack) -> Result<(), String> {
+/// - `Err(ForthError)` con el mensaje `"stack-underflow"` si la pila no contiene suficientes elementos.
+fn and(stack: &mut Stack) -> Result<(), ForthError> {
     let (a, b) = pop_two_operands(stack)?;
     stack.push(if a != FALSE && b != FALSE {
         TRUE
     } else {
         FALSE
-    });
-    Ok(())
+    })
 }
 
 /// Realiza la disyunción lógica (OR) entre los dos valores superiores de la pila.
@@ -160,15 +250,14 @@ fn and(stack: &mut Stack) -> Result<(), String> {
 /// # Retorna
 ///
 /// - `Ok(())` si la operación se realiza correctamente.
-/// - `Err(String)` con el mensaje `"stack-underflow"` si la pila no contiene suficientes elementos.
-fn or(stack: &mut Stack) -> Result<(), String> {
+/// - `Err(ForthError)` con el mensaje `"stack-underflow"` si la pila no contiene suficientes elementos.
+fn or(stack: &mut Stack) -> Result<(), ForthError> {
     let (a, b) = pop_two_operands(stack)?;
     stack.push(if a != FALSE || b != FALSE {
         TRUE
     } else {
         FALSE
-    });
-    Ok(())
+    })
 }
 
 /// Realiza la negación lógica (NOT) del valor superior de la pila.
@@ -184,13 +273,153 @@ fn or(stack: &mut Stack) -> Result<(), String> {
 /// # Retorna
 ///
 /// - `Ok(())` si la operación se realiza correctamente.
-/// - `Err(String)` con el mensaje `"stack-underflow"` si la pila no contiene al menos un elemento.
-fn not(stack: &mut Stack) -> Result<(), String> {
+/// - `Err(ForthError)` con el mensaje `"stack-underflow"` si la pila no contiene al menos un elemento.
+fn not(stack: &mut Stack) -> Result<(), ForthError> {
     match stack.pop() {
-        Some(a) => {
-            stack.push(if a != FALSE { FALSE } else { TRUE });
-            Ok(())
-        }
-        None => Err("stack-underflow".to_string()),
+        Some(a) => stack.push(if a != FALSE { FALSE } else { TRUE }),
+        None => Err(ForthError::StackUnderflow),
+    }
+}
+
+/// Compara los dos valores superiores de la pila y apila el resultado como valor booleano.
+///
+/// # Parámetros
+///
+/// - `stack`: Referencia mutable a la pila sobre la que se realizará la comparación.
+/// - `condition`: Condición a evaluar sobre el valor del tope y el que estaba debajo.
+///
+/// # Retorna
+///
+/// - `Ok(())` si la operación se realiza correctamente.
+/// - `Err(ForthError)` con el mensaje `"stack-underflow"` si la pila no contiene suficientes elementos.
+fn compare(stack: &mut Stack, condition: fn(Cell, Cell) -> bool) -> Result<(), ForthError> {
+    let (a, b) = pop_two_operands(stack)?;
+    stack.push(flag(condition(a, b)))
+}
+
+/// Compara los dos valores superiores de la pila interpretados sin signo y apila el resultado
+/// como valor booleano.
+///
+/// # Parámetros
+///
+/// - `stack`: Referencia mutable a la pila sobre la que se realizará la comparación.
+/// - `condition`: Condición a evaluar sobre el valor del tope y el que estaba debajo.
+///
+/// # Retorna
+///
+/// - `Ok(())` si la operación se realiza correctamente.
+/// - `Err(ForthError)` con el mensaje `"stack-underflow"` si la pila no contiene suficientes elementos.
+fn unsigned_compare(
+    stack: &mut Stack,
+    condition: fn(u128, u128) -> bool,
+) -> Result<(), ForthError> {
+    let (a, b) = pop_two_operands(stack)?;
+    let width = stack.width();
+    stack.push(flag(condition(width.unsigned(a), width.unsigned(b))))
+}
+
+/// Compara el valor superior de la pila con cero y apila el resultado como valor booleano.
+///
+/// # Parámetros
+///
+/// - `stack`: Referencia mutable a la pila sobre la que se realizará la comparación.
+/// - `condition`: Condición a evaluar sobre el valor del tope.
+///
+/// # Retorna
+///
+/// - `Ok(())` si la operación se realiza correctamente.
+/// - `Err(ForthError)` con el mensaje `"stack-underflow"` si la pila está vacía.
+fn compare_with_zero(stack: &mut Stack, condition: fn(Cell) -> bool) -> Result<(), ForthError> {
+    match stack.pop() {
+        Some(n) => stack.push(flag(condition(n))),
+        None => Err(ForthError::StackUnderflow),
     }
 }
+
+/// Evalúa si un valor está en el rango semiabierto `[lo, hi)` para `( n lo hi -- flag )`.
+///
+/// La comparación se hace sin signo sobre las distancias a `lo`, como indica el estándar, por lo
+/// que el rango da la vuelta cuando `hi` es menor que `lo`.
+///
+/// # Parámetros
+///
+/// - `stack`: Referencia mutable a la pila sobre la que se realizará la comparación.
+///
+/// # Retorna
+///
+/// - `Ok(())` si la operación se realiza correctamente.
+/// - `Err(ForthError)` con el mensaje `"stack-underflow"` si la pila no contiene tres elementos.
+fn within(stack: &mut Stack) -> Result<(), ForthError> {
+    if stack.len() < 3 {
+        return Err(ForthError::StackUnderflow);
+    }
+
+    let (hi, lo) = pop_two_operands(stack)?;
+    let n = stack.pop().ok_or(ForthError::StackUnderflow)?;
+    let width = stack.width();
+    stack.push(flag(
+        width.unsigned(n.wrapping_sub(lo)) < width.unsigned(hi.wrapping_sub(lo)),
+    ))
+}
+
+/// Combina bit a bit los dos valores superiores de la pila.
+///
+/// # Parámetros
+///
+/// - `stack`: Referencia mutable a la pila sobre la que se realizará la operación.
+/// - `operation`: Operación a aplicar sobre el valor del tope y el que estaba debajo.
+///
+/// # Retorna
+///
+/// - `Ok(())` si la operación se realiza correctamente.
+/// - `Err(ForthError)` con el mensaje `"stack-underflow"` si la pila no contiene suficientes elementos.
+fn bitwise(stack: &mut Stack, operation: fn(Cell, Cell) -> Cell) -> Result<(), ForthError> {
+    let (a, b) = pop_two_operands(stack)?;
+    stack.push(operation(a, b))
+}
+
+/// Invierte todos los bits del valor superior de la pila `( x -- ~x )`.
+///
+/// # Parámetros
+///
+/// - `stack`: Referencia mutable a la pila sobre la que se realizará la operación.
+///
+/// # Retorna
+///
+/// - `Ok(())` si la operación se realiza correctamente.
+/// - `Err(ForthError)` con el mensaje `"stack-underflow"` si la pila está vacía.
+fn invert(stack: &mut Stack) -> Result<(), ForthError> {
+    match stack.pop() {
+        Some(x) => stack.push(!x),
+        None => Err(ForthError::StackUnderflow),
+    }
+}
+
+/// Desplaza los bits del penúltimo valor de la pila tantas posiciones como indica el tope
+/// `( x u -- x' )`, rellenando con ceros.
+///
+/// El valor se interpreta sin signo y los bits que salen del ancho de la celda se descartan, por
+/// lo que un desplazamiento mayor o igual al ancho deja `0`.
+///
+/// # Parámetros
+///
+/// - `stack`: Referencia mutable a la pila sobre la que se realizará la operación.
+/// - `right`: Si es `true` se desplaza hacia la derecha (`RSHIFT`); si no, hacia la izquierda.
+///
+/// # Retorna
+///
+/// - `Ok(())` si la operación se realiza correctamente.
+/// - `Err(ForthError)` con el mensaje `"stack-underflow"` si la pila no contiene suficientes elementos.
+fn shift(stack: &mut Stack, right: bool) -> Result<(), ForthError> {
+    let (u, x) = pop_two_operands(stack)?;
+    let width = stack.width();
+    let amount = width.unsigned(u);
+    let bits = width.unsigned(x);
+
+    let result = match amount {
+        _ if amount >= u128::from(width.bits()) => 0,
+        _ if right => bits >> amount,
+        _ => bits << amount,
+    };
+    stack.push(width.wrap(result as DoubleCell))
+}
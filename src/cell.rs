@@ -0,0 +1,240 @@
+use crate::errors::ForthError;
+use std::str::FromStr;
+
+/// Valor almacenado en una celda de la pila, la pila de retorno o el espacio de datos.
+///
+/// Se representa siempre con 64 bits; el ancho de celda elegido ([`CellWidth`]) determina el
+/// rango de valores válidos y cómo se ajustan los resultados que lo exceden.
+pub type Cell = i64;
+
+/// Valor intermedio con el doble de bits que la celda más ancha, que permite calcular sin
+/// desborde los resultados de las operaciones entre celdas.
+pub type DoubleCell = i128;
+
+/// Base numérica inicial, usada también por `DECIMAL`.
+pub const DECIMAL_BASE: Cell = 10;
+
+/// Valida el contenido de `BASE`.
+///
+/// # Argumentos
+///
+/// * `base` - La base numérica, que debe estar entre 2 y 36.
+///
+/// # Retornos
+///
+/// Devuelve `Ok(u32)` con la base, o `Err(ForthError::InvalidArgument)` si está fuera de rango.
+pub fn radix(base: Cell) -> Result<u32, ForthError> {
+    u32::try_from(base)
+        .ok()
+        .filter(|radix| (2..=36).contains(radix))
+        .ok_or_else(|| ForthError::InvalidArgument(base.to_string()))
+}
+
+/// Escribe un número en la base indicada, con las letras en mayúsculas (`-FF` en base 16).
+///
+/// # Argumentos
+///
+/// * `value` - El número a escribir.
+/// * `radix` - La base, entre 2 y 36.
+pub fn format_number(value: DoubleCell, radix: u32) -> String {
+    let mut magnitude = value.unsigned_abs();
+    let mut digits = Vec::new();
+
+    loop {
+        let digit = (magnitude % u128::from(radix)) as u32;
+        digits.extend(char::from_digit(digit, radix).map(|c| c.to_ascii_uppercase()));
+        magnitude /= u128::from(radix);
+        if magnitude == 0 {
+            break;
+        }
+    }
+
+    if value < 0 {
+        digits.push('-');
+    }
+    digits.iter().rev().collect()
+}
+
+/// Interpreta un token como un número entero en la base indicada.
+///
+/// Se aceptan los prefijos de Forth-2012, que fijan la base sin importar la actual: `#`
+/// (decimal), `$` (hexadecimal) y `%` (binario), seguidos de un signo `-` o `+` opcional. Un caracter
+/// entre comillas simples, como `'A'`, representa su código.
+fn parse_number(token: &str, radix: u32) -> Option<DoubleCell> {
+    let mut chars = token.chars();
+    if let (Some('\''), Some(c), Some('\''), None) =
+        (chars.next(), chars.next(), chars.next(), chars.next())
+    {
+        return Some(DoubleCell::from(u32::from(c)));
+    }
+
+    let (digits, radix) = match token.chars().next() {
+        Some('#') => (&token[1..], 10),
+        Some('$') => (&token[1..], 16),
+        Some('%') => (&token[1..], 2),
+        _ => (token, radix),
+    };
+    let magnitude = digits.strip_prefix(['-', '+']).unwrap_or(digits);
+    if magnitude.is_empty() || !magnitude.chars().all(|c| c.is_digit(radix)) {
+        return None;
+    }
+
+    DoubleCell::from_str_radix(digits, radix).ok()
+}
+
+/// Ancho de las celdas del intérprete.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CellWidth {
+    /// Celdas de 16 bits, el ancho original del intérprete.
+    #[default]
+    Bits16,
+    /// Celdas de 32 bits.
+    Bits32,
+    /// Celdas de 64 bits.
+    Bits64,
+}
+
+impl CellWidth {
+    /// Obtiene la cantidad de bits de una celda.
+    pub fn bits(self) -> u32 {
+        match self {
+            CellWidth::Bits16 => 16,
+            CellWidth::Bits32 => 32,
+            CellWidth::Bits64 => 64,
+        }
+    }
+
+    /// Obtiene la cantidad de bytes que ocupa una celda.
+    pub fn bytes(self) -> usize {
+        self.bits() as usize / 8
+    }
+
+    /// Obtiene el menor valor representable en una celda.
+    pub fn min(self) -> Cell {
+        Cell::MIN >> (Cell::BITS - self.bits())
+    }
+
+    /// Obtiene el mayor valor representable en una celda.
+    pub fn max(self) -> Cell {
+        Cell::MAX >> (Cell::BITS - self.bits())
+    }
+
+    /// Indica si un valor entra en una celda sin desbordar.
+    ///
+    /// # Argumentos
+    ///
+    /// * `value` - El valor a verificar.
+    pub fn contains(self, value: DoubleCell) -> bool {
+        (DoubleCell::from(self.min())..=DoubleCell::from(self.max())).contains(&value)
+    }
+
+    /// Conserva los bits menos significativos de un valor (aritmética modular), interpretados
+    /// como un número con signo del ancho de la celda.
+    ///
+    /// # Argumentos
+    ///
+    /// * `value` - El valor a ajustar.
+    pub fn wrap(self, value: DoubleCell) -> Cell {
+        let shift = DoubleCell::BITS - self.bits();
+        ((value << shift) >> shift) as Cell
+    }
+
+    /// Conserva los bits menos significativos de un valor que entran en un número doble (dos
+    /// celdas), interpretados como un número con signo.
+    ///
+    /// # Argumentos
+    ///
+    /// * `value` - El valor a ajustar.
+    pub fn wrap_double(self, value: DoubleCell) -> DoubleCell {
+        let shift = DoubleCell::BITS - 2 * self.bits();
+        (value << shift) >> shift
+    }
+
+    /// Obtiene el valor sin signo representado por los bits de una celda.
+    ///
+    /// # Argumentos
+    ///
+    /// * `value` - El contenido de la celda.
+    pub fn unsigned(self, value: Cell) -> u128 {
+        value as u128 & (u128::MAX >> (u128::BITS - self.bits()))
+    }
+
+    /// Obtiene el valor sin signo representado por los bits de un número doble.
+    ///
+    /// # Argumentos
+    ///
+    /// * `value` - El número doble.
+    pub fn unsigned_double(self, value: DoubleCell) -> u128 {
+        value as u128 & (u128::MAX >> (u128::BITS - 2 * self.bits()))
+    }
+
+    /// Separa un número doble en sus dos celdas.
+    ///
+    /// # Argumentos
+    ///
+    /// * `value` - El número doble.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve la celda menos significativa y la más significativa, en ese orden (el orden en
+    /// que se apilan).
+    pub fn split(self, value: DoubleCell) -> (Cell, Cell) {
+        (self.wrap(value), self.wrap(value >> self.bits()))
+    }
+
+    /// Combina dos celdas en un número doble con signo.
+    ///
+    /// # Argumentos
+    ///
+    /// * `low` - La celda menos significativa.
+    /// * `high` - La celda más significativa, que contiene el signo.
+    pub fn join(self, low: Cell, high: Cell) -> DoubleCell {
+        (DoubleCell::from(high) << self.bits()) | self.unsigned(low) as DoubleCell
+    }
+
+    /// Interpreta un token como un número doble: un número terminado en `.`, como `100000.`.
+    ///
+    /// # Argumentos
+    ///
+    /// * `token` - El token a interpretar.
+    /// * `radix` - La base en la que se escriben los números sin prefijo.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve `Some(DoubleCell)` con el valor, o `None` si el token no es un número doble o no
+    /// entra en dos celdas.
+    pub fn parse_double(self, token: &str, radix: u32) -> Option<DoubleCell> {
+        parse_number(token.strip_suffix('.')?, radix)
+            .filter(|value| self.wrap_double(*value) == *value)
+    }
+
+    /// Interpreta un token como un número que entra en una celda, como `42`, `$FF` o `'A'`.
+    ///
+    /// # Argumentos
+    ///
+    /// * `token` - El token a interpretar.
+    /// * `radix` - La base en la que se escriben los números sin prefijo.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve `Some(Cell)` con el valor, o `None` si el token no es un número o no entra en una celda.
+    pub fn parse(self, token: &str, radix: u32) -> Option<Cell> {
+        parse_number(token, radix)
+            .filter(|value| self.contains(*value))
+            .map(|value| value as Cell)
+    }
+}
+
+impl FromStr for CellWidth {
+    type Err = ForthError;
+
+    /// Obtiene el ancho a partir de su cantidad de bits: `16`, `32` o `64`.
+    fn from_str(bits: &str) -> Result<Self, Self::Err> {
+        match bits {
+            "16" => Ok(CellWidth::Bits16),
+            "32" => Ok(CellWidth::Bits32),
+            "64" => Ok(CellWidth::Bits64),
+            _ => Err(ForthError::InvalidArgument(bits.to_string())),
+        }
+    }
+}
@@ -0,0 +1,694 @@
+use crate::arithmetic_operations::ArithmeticOp;
+use crate::boolean_operations::BooleanOp;
+use crate::cell::{Cell, CellWidth, radix};
+use crate::data_space::DataSpace;
+use crate::double_operations::DoubleOp;
+use crate::errors::ForthError;
+use crate::forth_basic_operations::ForthOp;
+use crate::input_operations::InputOp;
+use crate::memory_operations::MemoryOp;
+use crate::output_operations::{OutputOp, parse_string};
+use crate::pictured_output_operations::PicturedOutputOp;
+use crate::return_stack_operations::ReturnStackOp;
+use crate::string_operations::{StringOp, parse_escaped_string};
+use crate::tokenizer::{Source, Tokenizer};
+use crate::word_definitions::{DefiningOp, Definition, WordKind, WordMap, parse_word_name};
+
+/// Operadores aritméticos primitivos.
+const ARITHMETIC_OPERATORS: &[(&str, ArithmeticOp)] = &[
+    ("+", ArithmeticOp::Add),
+    ("-", ArithmeticOp::Subtract),
+    ("*", ArithmeticOp::Multiply),
+    ("/", ArithmeticOp::Divide),
+    ("MOD", ArithmeticOp::Mod),
+    ("/MOD", ArithmeticOp::DivMod),
+    ("NEGATE", ArithmeticOp::Negate),
+    ("ABS", ArithmeticOp::Abs),
+    ("MIN", ArithmeticOp::Min),
+    ("MAX", ArithmeticOp::Max),
+    ("1+", ArithmeticOp::Increment),
+    ("1-", ArithmeticOp::Decrement),
+    ("2*", ArithmeticOp::Double),
+    ("2/", ArithmeticOp::Half),
+];
+/// Operadores booleanos primitivos.
+const BOOLEAN_OPERATORS: &[(&str, BooleanOp)] = &[
+    ("=", BooleanOp::Equal),
+    ("<>", BooleanOp::NotEqual),
+    ("<", BooleanOp::Less),
+    (">", BooleanOp::Greater),
+    ("U<", BooleanOp::UnsignedLess),
+    ("U>", BooleanOp::UnsignedGreater),
+    ("0=", BooleanOp::ZeroEqual),
+    ("0<", BooleanOp::ZeroLess),
+    ("0>", BooleanOp::ZeroGreater),
+    ("WITHIN", BooleanOp::Within),
+    ("AND", BooleanOp::And),
+    ("OR", BooleanOp::Or),
+    ("XOR", BooleanOp::Xor),
+    ("INVERT", BooleanOp::Invert),
+    ("LSHIFT", BooleanOp::LeftShift),
+    ("RSHIFT", BooleanOp::RightShift),
+    ("NOT", BooleanOp::Not),
+];
+/// Operadores primitivos de manipulación de la pila.
+const FORTH_OPERATORS: &[(&str, ForthOp)] = &[
+    ("DUP", ForthOp::Dup),
+    ("DROP", ForthOp::Drop),
+    ("SWAP", ForthOp::Swap),
+    ("OVER", ForthOp::Over),
+    ("ROT", ForthOp::Rot),
+    ("-ROT", ForthOp::MinusRot),
+    ("NIP", ForthOp::Nip),
+    ("TUCK", ForthOp::Tuck),
+    ("?DUP", ForthOp::QuestionDup),
+    ("PICK", ForthOp::Pick),
+    ("ROLL", ForthOp::Roll),
+    ("DEPTH", ForthOp::Depth),
+    ("CLEAR", ForthOp::Clear),
+    ("2DUP", ForthOp::TwoDup),
+    ("2DROP", ForthOp::TwoDrop),
+    ("2SWAP", ForthOp::TwoSwap),
+    ("2OVER", ForthOp::TwoOver),
+];
+/// Operadores primitivos con números dobles.
+const DOUBLE_OPERATORS: &[(&str, DoubleOp)] = &[
+    ("D+", DoubleOp::DoubleAdd),
+    ("D-", DoubleOp::DoubleSubtract),
+    ("DNEGATE", DoubleOp::DoubleNegate),
+    ("M*", DoubleOp::MixedMultiply),
+    ("UM*", DoubleOp::UnsignedMixedMultiply),
+    ("UM/MOD", DoubleOp::UnsignedMixedDivMod),
+    ("FM/MOD", DoubleOp::FlooredDivMod),
+    ("SM/REM", DoubleOp::SymmetricDivRem),
+    ("*/", DoubleOp::Scale),
+    ("*/MOD", DoubleOp::ScaleMod),
+];
+/// Operadores primitivos de salida.
+const OUTPUT_OPERATORS: &[(&str, OutputOp)] = &[
+    ("CR", OutputOp::Cr),
+    (".", OutputOp::Point),
+    ("U.", OutputOp::UnsignedPoint),
+    ("D.", OutputOp::DoublePoint),
+    (".R", OutputOp::RightAligned),
+    ("U.R", OutputOp::UnsignedRightAligned),
+    ("D.R", OutputOp::DoubleRightAligned),
+    (".S", OutputOp::PrintStack),
+    ("EMIT", OutputOp::Emit),
+    ("XEMIT", OutputOp::ExtendedEmit),
+    ("SPACE", OutputOp::Space),
+    ("SPACES", OutputOp::Spaces),
+    ("BL", OutputOp::Blank),
+];
+/// Operadores primitivos de entrada.
+const INPUT_OPERATORS: &[(&str, InputOp)] = &[
+    ("KEY", InputOp::Key),
+    ("XKEY", InputOp::ExtendedKey),
+    ("KEY?", InputOp::KeyReady),
+    ("ACCEPT", InputOp::Accept),
+    ("EXPECT", InputOp::Expect),
+];
+/// Operadores primitivos de conversión numérica.
+const PICTURED_OUTPUT_OPERATORS: &[(&str, PicturedOutputOp)] = &[
+    ("<#", PicturedOutputOp::Begin),
+    ("#", PicturedOutputOp::Digit),
+    ("#S", PicturedOutputOp::Digits),
+    ("HOLD", PicturedOutputOp::Hold),
+    ("SIGN", PicturedOutputOp::Sign),
+    ("#>", PicturedOutputOp::End),
+];
+/// Operadores primitivos sobre cadenas.
+const STRING_OPERATORS: &[(&str, StringOp)] = &[
+    ("TYPE", StringOp::Type),
+    ("COUNT", StringOp::Count),
+    ("XC-SIZE", StringOp::CharSize),
+];
+/// Operadores primitivos de la pila de retorno.
+const RETURN_STACK_OPERATORS: &[(&str, ReturnStackOp)] = &[
+    (">R", ReturnStackOp::ToR),
+    ("R>", ReturnStackOp::RFrom),
+    ("R@", ReturnStackOp::RFetch),
+    ("2>R", ReturnStackOp::TwoToR),
+    ("2R>", ReturnStackOp::TwoRFrom),
+];
+/// Operadores primitivos del espacio de datos.
+const MEMORY_OPERATORS: &[(&str, MemoryOp)] = &[
+    ("@", MemoryOp::Fetch),
+    ("!", MemoryOp::Store),
+    ("+!", MemoryOp::PlusStore),
+    ("?", MemoryOp::PrintCell),
+    ("C@", MemoryOp::CharFetch),
+    ("C!", MemoryOp::CharStore),
+    ("HERE", MemoryOp::Here),
+    ("ALLOT", MemoryOp::Allot),
+    (",", MemoryOp::Comma),
+    ("CELLS", MemoryOp::Cells),
+    ("BASE", MemoryOp::Base),
+    ("DECIMAL", MemoryOp::Decimal),
+    ("HEX", MemoryOp::Hex),
+    ("BINARY", MemoryOp::Binary),
+    ("OCTAL", MemoryOp::Octal),
+    ("SPAN", MemoryOp::Span),
+];
+/// Operadores primitivos sobre palabras definidas.
+const DEFINING_OPERATORS: &[(&str, DefiningOp)] = &[(">BODY", DefiningOp::ToBody)];
+/// Palabras que el compilador traduce a instrucciones específicas en lugar de primitivas.
+const COMPILER_WORDS: &[&str] = &[
+    ":", ";", "DOES>", "IF", "ELSE", "THEN", "DO", "LOOP", "+LOOP", "LEAVE", "BEGIN", "UNTIL",
+    "AGAIN", "WHILE", "REPEAT", "I", "J", "UNLOOP", ".\"", "S\"", "S\\\"", "C\"", "TO", "'",
+    "VARIABLE", "CONSTANT", "VALUE", "CREATE",
+];
+
+/// Instrucción de la representación intermedia que ejecuta el intérprete.
+///
+/// Las definiciones de palabras y el código de nivel superior se compilan una única vez a una
+/// lista de instrucciones; los saltos y ciclos referencian posiciones dentro de esa lista.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Instruction {
+    /// Apila un número.
+    Literal(Cell),
+    /// Ejecuta una operación primitiva.
+    Primitive(Primitive),
+    /// Ejecuta la palabra definida con el token de ejecución indicado.
+    Call(usize),
+    /// Salta incondicionalmente a la posición indicada.
+    Branch(usize),
+    /// Toma una condición de la pila y salta a la posición indicada si es cero.
+    BranchIfZero(usize),
+    /// Inicia un ciclo contado tomando el índice inicial y el límite de la pila.
+    Do,
+    /// Incrementa el índice en uno y vuelve al inicio del cuerpo indicado si el ciclo no terminó.
+    Loop(usize),
+    /// Incrementa el índice con el tope de la pila y vuelve al inicio del cuerpo si el ciclo no terminó.
+    PlusLoop(usize),
+    /// Abandona el ciclo más interno saltando a la posición indicada.
+    Leave(usize),
+    /// Apila el índice del ciclo ubicado la cantidad de niveles indicada por debajo del más interno.
+    LoopIndex(usize),
+    /// Descarta los parámetros del ciclo más interno.
+    Unloop,
+    /// Imprime una cadena de texto.
+    PrintString(String),
+    /// Define una palabra con `VARIABLE`, `CONSTANT`, `VALUE` o `CREATE`. Si no se indica el
+    /// nombre, se toma de la entrada al momento de ejecutarse.
+    Define(DefiningWord, Option<String>),
+    /// Almacena el tope de la pila en la celda de un `VALUE`.
+    StoreValue(Cell),
+    /// Asigna el código con el token de ejecución indicado a la última palabra creada.
+    Does(usize),
+}
+
+/// Lista de instrucciones compiladas junto con el token del programa del que proviene cada una.
+#[derive(Debug, Default, PartialEq)]
+pub struct Code {
+    pub instructions: Vec<Instruction>,
+    pub sources: Vec<Source>,
+}
+
+impl From<Vec<Instruction>> for Code {
+    fn from(instructions: Vec<Instruction>) -> Self {
+        Code {
+            instructions,
+            sources: Vec::new(),
+        }
+    }
+}
+
+/// Operación primitiva, agrupada según el módulo que la implementa. El nombre de la palabra se
+/// resuelve al compilar, de modo que la ejecución no compara cadenas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Primitive {
+    Arithmetic(ArithmeticOp),
+    Boolean(BooleanOp),
+    Forth(ForthOp),
+    Double(DoubleOp),
+    Input(InputOp),
+    Output(OutputOp),
+    PicturedOutput(PicturedOutputOp),
+    String(StringOp),
+    ReturnStack(ReturnStackOp),
+    Memory(MemoryOp),
+    Defining(DefiningOp),
+}
+
+/// Palabras que definen una nueva palabra tomando su nombre de la entrada.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DefiningWord {
+    Variable,
+    Constant,
+    Value,
+    Create,
+}
+
+/// Estructura de control abierta durante la compilación, pendiente de su cierre.
+enum Control {
+    /// `IF` con la posición del salto condicional a completar.
+    If(usize),
+    /// `ELSE` con la posición del salto incondicional a completar.
+    Else(usize),
+    /// `DO` con el inicio del cuerpo y las posiciones de los `LEAVE` a completar.
+    Do { start: usize, leaves: Vec<usize> },
+    /// `BEGIN` con el inicio del cuerpo.
+    Begin(usize),
+    /// `WHILE` con el inicio del ciclo y la posición del salto de salida a completar.
+    While { start: usize, exit: usize },
+}
+
+/// Compilador de tokens a instrucciones.
+struct Compiler<'a> {
+    code: Code,
+    source: Source,
+    control: Vec<Control>,
+    word_map: &'a mut WordMap,
+    data_space: &'a mut DataSpace,
+    width: CellWidth,
+    in_definition: bool,
+}
+
+/// Compila un elemento de nivel superior a partir de su primer token.
+///
+/// Si el token abre una estructura de control (`IF`, `DO`, `BEGIN`), se compilan también los
+/// tokens siguientes hasta su cierre, de modo que la estructura completa se ejecute a partir de
+/// una única lista de instrucciones.
+///
+/// # Parámetros
+/// - `token`: Primer token del elemento.
+/// - `tokens`: Iterador de los tokens restantes de la entrada.
+/// - `word_map`: Mapa de palabras definidas por el usuario.
+/// - `data_space`: Espacio de datos donde se almacenan las cadenas literales.
+/// - `width`: Ancho de las celdas, que determina el rango de los literales numéricos.
+///
+/// # Retornos
+/// - `Ok(Code)` con las instrucciones compiladas.
+/// - `Err(ForthError)` si el elemento no puede compilarse o una estructura de control no se cierra.
+pub fn compile_item(
+    token: &str,
+    tokens: &mut Tokenizer,
+    word_map: &mut WordMap,
+    data_space: &mut DataSpace,
+    width: CellWidth,
+) -> Result<Code, ForthError> {
+    let mut compiler = Compiler::new(word_map, data_space, width, false);
+    compiler.compile_token(token, tokens)?;
+
+    while let Some(open) = compiler.control.last() {
+        let missing = unterminated_error(open);
+        let token = tokens.next().ok_or(missing)?;
+        compiler.compile_token(token, tokens)?;
+    }
+
+    Ok(compiler.code)
+}
+
+/// Compila el cuerpo de una definición hasta el símbolo `;`.
+///
+/// Si el cuerpo contiene `DOES>`, los tokens que lo siguen se compilan como una definición
+/// anónima que pasa a ser el comportamiento de la última palabra creada con `CREATE`.
+///
+/// # Parámetros
+/// - `tokens`: Iterador de los tokens restantes de la entrada.
+/// - `word_map`: Mapa de palabras definidas por el usuario.
+/// - `data_space`: Espacio de datos donde se almacenan las cadenas literales.
+/// - `width`: Ancho de las celdas, que determina el rango de los literales numéricos.
+///
+/// # Retornos
+/// - `Ok(Code)` con las instrucciones del cuerpo.
+/// - `Err(ForthError)` si no se encuentra `;`, si una estructura de control no se cierra o si el
+///   cuerpo contiene una palabra desconocida.
+pub fn compile_definition(
+    tokens: &mut Tokenizer,
+    word_map: &mut WordMap,
+    data_space: &mut DataSpace,
+    width: CellWidth,
+) -> Result<Code, ForthError> {
+    let mut compiler = Compiler::new(word_map, data_space, width, true);
+
+    while let Some(token) = tokens.next() {
+        if (token == ";" || token.eq_ignore_ascii_case("DOES>"))
+            && let Some(open) = compiler.control.last()
+        {
+            return Err(ForthError::MismatchedControl(
+                token.to_string(),
+                closers(open),
+            ));
+        }
+
+        if token == ";" {
+            return Ok(compiler.code);
+        }
+
+        if token.eq_ignore_ascii_case("DOES>") {
+            compiler.source = tokens.source();
+            let does_code =
+                compile_definition(tokens, compiler.word_map, compiler.data_space, width)?;
+            let xt = compiler
+                .word_map
+                .add_anonymous(Definition::new(does_code, WordKind::Colon));
+            compiler.emit(Instruction::Does(xt));
+            return Ok(compiler.code);
+        }
+
+        compiler.compile_token(token, tokens)?;
+    }
+
+    Err(ForthError::UnterminatedDefinition)
+}
+
+impl<'a> Compiler<'a> {
+    fn new(
+        word_map: &'a mut WordMap,
+        data_space: &'a mut DataSpace,
+        width: CellWidth,
+        in_definition: bool,
+    ) -> Self {
+        Compiler {
+            code: Code::default(),
+            source: Source::default(),
+            control: Vec::new(),
+            word_map,
+            data_space,
+            width,
+            in_definition,
+        }
+    }
+
+    /// Compila un token, agregando sus instrucciones al final del código.
+    ///
+    /// Las palabras definidas por el usuario tienen prioridad sobre las primitivas, y los números
+    /// se compilan como literales. Cualquier otro token produce el error `?`.
+    fn compile_token(&mut self, token: &str, tokens: &mut Tokenizer) -> Result<(), ForthError> {
+        self.source = tokens.source();
+        let token_up = token.to_uppercase();
+
+        if let Some(xt) = self.word_map.find(&token_up) {
+            self.emit(Instruction::Call(xt));
+            return Ok(());
+        }
+
+        if self.compile_control(&token_up)? {
+            return Ok(());
+        }
+
+        let instruction = match token_up.as_str() {
+            "I" => Instruction::LoopIndex(0),
+            "J" => Instruction::LoopIndex(1),
+            "UNLOOP" => Instruction::Unloop,
+            "(" => return Err(ForthError::UnterminatedComment),
+            ".\"" => Instruction::PrintString(parse_string(tokens)?),
+            "S\"" => return self.compile_string(&parse_string(tokens)?, false),
+            "S\\\"" => return self.compile_string(&parse_escaped_string(tokens)?, false),
+            "C\"" => return self.compile_string(&parse_string(tokens)?, true),
+            "TO" => self.compile_to(tokens)?,
+            "'" => self.compile_tick(tokens)?,
+            "VARIABLE" => self.compile_defining(DefiningWord::Variable, tokens)?,
+            "CONSTANT" => self.compile_defining(DefiningWord::Constant, tokens)?,
+            "VALUE" => self.compile_defining(DefiningWord::Value, tokens)?,
+            "CREATE" => self.compile_defining(DefiningWord::Create, tokens)?,
+            _ => match find_primitive(&token_up) {
+                Some(primitive) => Instruction::Primitive(primitive),
+                None => return self.compile_number(token),
+            },
+        };
+
+        self.emit(instruction);
+        Ok(())
+    }
+
+    /// Compila un número, escrito en la base de `BASE` o con un prefijo de base, como literal.
+    /// Los números terminados en `.` son dobles y se compilan como dos literales, con la celda
+    /// más significativa al final.
+    ///
+    /// # Retornos
+    /// - `Ok(())` si el token es un número que entra en una celda (o en dos, si es doble).
+    /// - `Err(ForthError::InvalidArgument)` si `BASE` no contiene una base válida.
+    /// - `Err(ForthError::UnknownWord)` si el token no es un número.
+    fn compile_number(&mut self, token: &str) -> Result<(), ForthError> {
+        let radix = radix(self.data_space.base())?;
+        if let Some(value) = self.width.parse(token, radix) {
+            self.emit(Instruction::Literal(value));
+            return Ok(());
+        }
+
+        let value = self
+            .width
+            .parse_double(token, radix)
+            .ok_or_else(|| ForthError::UnknownWord(token.to_uppercase()))?;
+        let (low, high) = self.width.split(value);
+        self.emit(Instruction::Literal(low));
+        self.emit(Instruction::Literal(high));
+        Ok(())
+    }
+
+    /// Almacena una cadena literal en el espacio de datos, un caracter por celda, y la compila
+    /// como los literales de su dirección y su longitud (`S"`). Las cadenas con contador (`C"`)
+    /// guardan la longitud en la primera celda y se compilan solo como su dirección.
+    ///
+    /// # Retornos
+    /// - `Ok(())` si la cadena se almacenó.
+    /// - `Err(ForthError::DataSpaceOverflow)` si no hay lugar en el espacio de datos.
+    fn compile_string(&mut self, text: &str, counted: bool) -> Result<(), ForthError> {
+        let address = self.data_space.here() as Cell;
+        let length = text.len() as Cell;
+
+        if counted {
+            self.data_space.append(length)?;
+        }
+        for byte in text.bytes() {
+            self.data_space.append(Cell::from(byte))?;
+        }
+
+        self.emit(Instruction::Literal(address));
+        if !counted {
+            self.emit(Instruction::Literal(length));
+        }
+        Ok(())
+    }
+
+    /// Compila las palabras de las estructuras de control.
+    ///
+    /// # Retornos
+    /// - `Ok(true)` si el token era una palabra de control y se compiló.
+    /// - `Ok(false)` si el token no es una palabra de control.
+    /// - `Err(ForthError)` si la palabra no corresponde con la estructura abierta.
+    fn compile_control(&mut self, token: &str) -> Result<bool, ForthError> {
+        let position = self.code.instructions.len();
+
+        match token {
+            "IF" => {
+                self.control.push(Control::If(position));
+                self.emit(Instruction::BranchIfZero(0));
+            }
+            "ELSE" => match self.control.pop() {
+                Some(Control::If(branch)) => {
+                    self.emit(Instruction::Branch(0));
+                    self.patch(branch, position + 1);
+                    self.control.push(Control::Else(position));
+                }
+                other => return Err(mismatch_error(other, token)),
+            },
+            "THEN" => match self.control.pop() {
+                Some(Control::If(branch) | Control::Else(branch)) => self.patch(branch, position),
+                other => return Err(mismatch_error(other, token)),
+            },
+            "DO" => {
+                self.emit(Instruction::Do);
+                self.control.push(Control::Do {
+                    start: position + 1,
+                    leaves: Vec::new(),
+                });
+            }
+            "LOOP" | "+LOOP" => match self.control.pop() {
+                Some(Control::Do { start, leaves }) => {
+                    self.emit(if token == "LOOP" {
+                        Instruction::Loop(start)
+                    } else {
+                        Instruction::PlusLoop(start)
+                    });
+                    for leave in leaves {
+                        self.patch(leave, position + 1);
+                    }
+                }
+                other => return Err(mismatch_error(other, token)),
+            },
+            "LEAVE" => {
+                let leaves = self
+                    .control
+                    .iter_mut()
+                    .rev()
+                    .find_map(|control| match control {
+                        Control::Do { leaves, .. } => Some(leaves),
+                        _ => None,
+                    })
+                    .ok_or(ForthError::OutsideLoop("LEAVE"))?;
+                leaves.push(position);
+                self.emit(Instruction::Leave(0));
+            }
+            "BEGIN" => self.control.push(Control::Begin(position)),
+            "UNTIL" | "AGAIN" => match self.control.pop() {
+                Some(Control::Begin(start)) => self.emit(if token == "UNTIL" {
+                    Instruction::BranchIfZero(start)
+                } else {
+                    Instruction::Branch(start)
+                }),
+                other => return Err(mismatch_error(other, token)),
+            },
+            "WHILE" => match self.control.pop() {
+                Some(Control::Begin(start)) => {
+                    self.control.push(Control::While {
+                        start,
+                        exit: position,
+                    });
+                    self.emit(Instruction::BranchIfZero(0));
+                }
+                other => return Err(mismatch_error(other, token)),
+            },
+            "REPEAT" => match self.control.pop() {
+                Some(Control::While { start, exit }) => {
+                    self.emit(Instruction::Branch(start));
+                    self.patch(exit, position + 1);
+                }
+                other => return Err(mismatch_error(other, token)),
+            },
+            _ => return Ok(false),
+        }
+
+        Ok(true)
+    }
+
+    /// Compila `TO <nombre>`, que debe referirse a una palabra definida con `VALUE`.
+    fn compile_to(&mut self, tokens: &mut Tokenizer) -> Result<Instruction, ForthError> {
+        let name = next_name(tokens)?;
+
+        match self.word_map.get(&name).map(|definition| definition.kind) {
+            Some(WordKind::Value(address)) => Ok(Instruction::StoreValue(address)),
+            Some(_) => Err(ForthError::NotAValue(name)),
+            None => Err(ForthError::UnknownWord(name)),
+        }
+    }
+
+    /// Compila `' <nombre>` como el literal del token de ejecución de la palabra.
+    fn compile_tick(&mut self, tokens: &mut Tokenizer) -> Result<Instruction, ForthError> {
+        let name = next_name(tokens)?;
+        let xt = self
+            .word_map
+            .find(&name)
+            .ok_or_else(|| ForthError::UnknownWord(name.clone()))?;
+        Ok(Instruction::Literal(xt as Cell))
+    }
+
+    /// Compila una palabra definidora. Fuera de una definición el nombre se toma en este momento;
+    /// dentro de una definición se toma de la entrada cuando la palabra se ejecuta.
+    fn compile_defining(
+        &mut self,
+        defining_word: DefiningWord,
+        tokens: &mut Tokenizer,
+    ) -> Result<Instruction, ForthError> {
+        let name = if self.in_definition {
+            None
+        } else {
+            Some(parse_word_name(tokens)?)
+        };
+
+        Ok(Instruction::Define(defining_word, name))
+    }
+
+    /// Agrega una instrucción al final del código, asociada al token que se está compilando.
+    fn emit(&mut self, instruction: Instruction) {
+        self.code.instructions.push(instruction);
+        self.code.sources.push(self.source.clone());
+    }
+
+    /// Completa el destino de un salto compilado previamente.
+    fn patch(&mut self, position: usize, target: usize) {
+        if let Some(
+            Instruction::Branch(destination)
+            | Instruction::BranchIfZero(destination)
+            | Instruction::Leave(destination),
+        ) = self.code.instructions.get_mut(position)
+        {
+            *destination = target;
+        }
+    }
+}
+
+/// Obtiene los nombres de todas las palabras predefinidas del intérprete.
+pub fn builtin_words() -> impl Iterator<Item = &'static str> {
+    names(ARITHMETIC_OPERATORS)
+        .chain(names(BOOLEAN_OPERATORS))
+        .chain(names(FORTH_OPERATORS))
+        .chain(names(DOUBLE_OPERATORS))
+        .chain(names(INPUT_OPERATORS))
+        .chain(names(OUTPUT_OPERATORS))
+        .chain(names(PICTURED_OUTPUT_OPERATORS))
+        .chain(names(STRING_OPERATORS))
+        .chain(names(RETURN_STACK_OPERATORS))
+        .chain(names(MEMORY_OPERATORS))
+        .chain(names(DEFINING_OPERATORS))
+        .chain(COMPILER_WORDS.iter().copied())
+}
+
+/// Obtiene los nombres de las palabras de una tabla de operadores primitivos.
+fn names<T>(operators: &'static [(&'static str, T)]) -> impl Iterator<Item = &'static str> {
+    operators.iter().map(|(name, _)| *name)
+}
+
+/// Busca en una tabla de operadores primitivos la operación con el nombre indicado.
+fn find<T: Copy>(operators: &[(&str, T)], token: &str) -> Option<T> {
+    operators
+        .iter()
+        .find(|(name, _)| *name == token)
+        .map(|(_, operator)| *operator)
+}
+
+/// Busca el operador primitivo correspondiente a un token.
+///
+/// # Retornos
+/// Devuelve `Some(Primitive)` si el token es una operación primitiva, o `None` en caso contrario.
+fn find_primitive(token: &str) -> Option<Primitive> {
+    find(ARITHMETIC_OPERATORS, token)
+        .map(Primitive::Arithmetic)
+        .or_else(|| find(BOOLEAN_OPERATORS, token).map(Primitive::Boolean))
+        .or_else(|| find(FORTH_OPERATORS, token).map(Primitive::Forth))
+        .or_else(|| find(DOUBLE_OPERATORS, token).map(Primitive::Double))
+        .or_else(|| find(INPUT_OPERATORS, token).map(Primitive::Input))
+        .or_else(|| find(OUTPUT_OPERATORS, token).map(Primitive::Output))
+        .or_else(|| find(PICTURED_OUTPUT_OPERATORS, token).map(Primitive::PicturedOutput))
+        .or_else(|| find(STRING_OPERATORS, token).map(Primitive::String))
+        .or_else(|| find(RETURN_STACK_OPERATORS, token).map(Primitive::ReturnStack))
+        .or_else(|| find(MEMORY_OPERATORS, token).map(Primitive::Memory))
+        .or_else(|| find(DEFINING_OPERATORS, token).map(Primitive::Defining))
+}
+
+/// Toma de la entrada el nombre de la palabra sobre la que opera `TO` o `'`.
+fn next_name(tokens: &mut Tokenizer) -> Result<String, ForthError> {
+    tokens
+        .next()
+        .map(|name| name.to_uppercase())
+        .ok_or(ForthError::MissingWordName)
+}
+
+/// Construye el mensaje de error para una palabra de cierre que no corresponde con la estructura abierta.
+///
+/// Si hay una estructura abierta se informa el cierre que falta; en caso contrario, que la palabra
+/// no tiene su apertura.
+fn mismatch_error(open: Option<Control>, token: &str) -> ForthError {
+    match open {
+        Some(control) => ForthError::MismatchedControl(token.to_string(), closers(&control)),
+        None => ForthError::UnmatchedControl(token.to_string()),
+    }
+}
+
+/// Construye el mensaje de error para una estructura de control que no fue cerrada.
+fn unterminated_error(control: &Control) -> ForthError {
+    ForthError::UnterminatedControl(closers(control))
+}
+
+/// Obtiene las palabras que cierran una estructura de control abierta.
+fn closers(control: &Control) -> &'static str {
+    match control {
+        Control::If(_) | Control::Else(_) => "'THEN'",
+        Control::Do { .. } => "'LOOP'",
+        Control::Begin(_) => "'UNTIL' o 'REPEAT'",
+        Control::While { .. } => "'REPEAT'",
+    }
+}
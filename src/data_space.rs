@@ -0,0 +1,244 @@
+use crate::cell::{Cell, DECIMAL_BASE, DoubleCell};
+use crate::errors::ForthError;
+
+/// Cantidad de caracteres del área de conversión numérica.
+pub const PICTURED_SIZE: usize = 256;
+
+/// Espacio de datos del intérprete, direccionado por celdas.
+///
+/// Cada dirección corresponde a una celda. Solo las celdas reservadas (desde la
+/// dirección `0` hasta `HERE`) pueden leerse o escribirse; cualquier acceso fuera de ese rango
+/// devuelve el error `invalid-memory-address`.
+///
+/// La variable `BASE` ocupa la dirección siguiente a la última celda reservable, de modo que
+/// puede leerse y escribirse con `@` y `!` sin alterar `HERE`. A continuación se encuentra el
+/// área de conversión numérica, donde `<#`, `HOLD` y `#>` construyen texto de derecha a izquierda,
+/// y luego la variable `SPAN`, con la cantidad de caracteres leídos por el último `EXPECT`.
+pub struct DataSpace {
+    cells: Vec<Cell>,
+    max_size: usize,
+    base: Cell,
+    span: Cell,
+    pictured: Vec<Cell>,
+    pictured_start: usize,
+}
+
+impl DataSpace {
+    /// Crea un espacio de datos vacío con capacidad para `max_size` celdas.
+    ///
+    /// # Argumentos
+    ///
+    /// * `max_size` - La cantidad máxima de celdas que pueden reservarse.
+    pub fn new(max_size: usize) -> Self {
+        DataSpace {
+            cells: Vec::new(),
+            max_size,
+            base: DECIMAL_BASE,
+            span: 0,
+            pictured: vec![0; PICTURED_SIZE],
+            pictured_start: PICTURED_SIZE,
+        }
+    }
+
+    /// Obtiene la dirección de la variable `BASE`.
+    pub fn base_address(&self) -> Cell {
+        self.max_size as Cell
+    }
+
+    /// Obtiene la base numérica actual, almacenada en `BASE`.
+    pub fn base(&self) -> Cell {
+        self.base
+    }
+
+    /// Cambia la base numérica almacenada en `BASE`.
+    ///
+    /// # Argumentos
+    ///
+    /// * `base` - La nueva base.
+    pub fn set_base(&mut self, base: Cell) {
+        self.base = base;
+    }
+
+    /// Obtiene la dirección de la variable `SPAN`.
+    pub fn span_address(&self) -> Cell {
+        self.pictured_address() + PICTURED_SIZE as Cell
+    }
+
+    /// Cambia la cantidad de caracteres almacenada en `SPAN`.
+    ///
+    /// # Argumentos
+    ///
+    /// * `span` - La cantidad de caracteres leídos.
+    pub fn set_span(&mut self, span: Cell) {
+        self.span = span;
+    }
+
+    /// Vacía el área de conversión numérica para comenzar un nuevo texto.
+    pub fn begin_pictured(&mut self) {
+        self.pictured_start = PICTURED_SIZE;
+    }
+
+    /// Agrega un caracter al comienzo del texto del área de conversión numérica.
+    ///
+    /// # Argumentos
+    ///
+    /// * `char` - El código del caracter a agregar.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve `Ok(())` si se agregó el caracter, o `Err(ForthError::PicturedOutputOverflow)` si el área está llena.
+    pub fn hold(&mut self, char: Cell) -> Result<(), ForthError> {
+        if self.pictured_start == 0 {
+            return Err(ForthError::PicturedOutputOverflow);
+        }
+
+        self.pictured_start -= 1;
+        self.pictured[self.pictured_start] = char;
+        Ok(())
+    }
+
+    /// Obtiene el texto del área de conversión numérica.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve la dirección de su primer caracter y su longitud.
+    pub fn pictured(&self) -> (Cell, Cell) {
+        let address = self.pictured_address() + self.pictured_start as Cell;
+        (address, (PICTURED_SIZE - self.pictured_start) as Cell)
+    }
+
+    /// Obtiene la dirección de la próxima celda libre.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve la cantidad de celdas reservadas hasta el momento.
+    pub fn here(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Reserva (o libera, si `count` es negativo) la cantidad de celdas indicada.
+    ///
+    /// Las celdas nuevas se inicializan en cero.
+    ///
+    /// # Argumentos
+    ///
+    /// * `count` - Cantidad de celdas a reservar.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve `Ok(())` si la reserva es válida, o un `Err` con:
+    /// - `"data-space-overflow"` si se supera la capacidad del espacio de datos.
+    /// - `"invalid-memory-address"` si se intenta liberar más celdas de las reservadas.
+    pub fn allot(&mut self, count: Cell) -> Result<(), ForthError> {
+        let new_size = DoubleCell::from(count) + self.here() as DoubleCell;
+
+        if new_size < 0 {
+            return Err(ForthError::InvalidMemoryAddress);
+        }
+        if new_size > self.max_size as DoubleCell {
+            return Err(ForthError::DataSpaceOverflow);
+        }
+
+        self.cells.resize(new_size as usize, 0);
+        Ok(())
+    }
+
+    /// Libera las celdas reservadas a partir de la dirección indicada, si las hay.
+    ///
+    /// # Argumentos
+    ///
+    /// * `address` - La primera celda a liberar.
+    pub fn release_from(&mut self, address: usize) {
+        self.cells.truncate(address);
+    }
+
+    /// Reserva una celda y almacena en ella el valor indicado.
+    ///
+    /// # Argumentos
+    ///
+    /// * `value` - El valor a almacenar.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve `Ok(usize)` con la dirección de la celda reservada, o `Err(ForthError::DataSpaceOverflow)` si no hay lugar.
+    pub fn append(&mut self, value: Cell) -> Result<usize, ForthError> {
+        let address = self.here();
+        self.allot(1)?;
+        self.store(address as Cell, value)?;
+        Ok(address)
+    }
+
+    /// Lee el valor almacenado en una dirección.
+    ///
+    /// # Argumentos
+    ///
+    /// * `address` - La dirección de la celda a leer.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve `Ok(Cell)` con el valor, o `Err(ForthError::InvalidMemoryAddress)` si la dirección no está reservada.
+    pub fn fetch(&self, address: Cell) -> Result<Cell, ForthError> {
+        if address == self.base_address() {
+            return Ok(self.base);
+        }
+        if address == self.span_address() {
+            return Ok(self.span);
+        }
+        if let Some(index) = self.pictured_index(address) {
+            return Ok(self.pictured[index]);
+        }
+
+        let index = self.index(address)?;
+        Ok(self.cells[index])
+    }
+
+    /// Almacena un valor en una dirección.
+    ///
+    /// # Argumentos
+    ///
+    /// * `address` - La dirección de la celda a escribir.
+    /// * `value` - El valor a almacenar.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve `Ok(())` si se escribió el valor, o `Err(ForthError::InvalidMemoryAddress)` si la dirección no está reservada.
+    pub fn store(&mut self, address: Cell, value: Cell) -> Result<(), ForthError> {
+        if address == self.base_address() {
+            self.base = value;
+            return Ok(());
+        }
+        if address == self.span_address() {
+            self.span = value;
+            return Ok(());
+        }
+        if let Some(index) = self.pictured_index(address) {
+            self.pictured[index] = value;
+            return Ok(());
+        }
+
+        let index = self.index(address)?;
+        self.cells[index] = value;
+        Ok(())
+    }
+
+    /// Obtiene la dirección del primer caracter del área de conversión numérica.
+    fn pictured_address(&self) -> Cell {
+        self.base_address() + 1
+    }
+
+    /// Convierte una dirección del área de conversión numérica en un índice de esa área.
+    fn pictured_index(&self, address: Cell) -> Option<usize> {
+        address
+            .checked_sub(self.pictured_address())
+            .and_then(|offset| usize::try_from(offset).ok())
+            .filter(|index| *index < PICTURED_SIZE)
+    }
+
+    /// Valida una dirección y la convierte en un índice de celda.
+    fn index(&self, address: Cell) -> Result<usize, ForthError> {
+        usize::try_from(address)
+            .ok()
+            .filter(|index| *index < self.cells.len())
+            .ok_or(ForthError::InvalidMemoryAddress)
+    }
+}
@@ -0,0 +1,233 @@
+use crate::arithmetic_operations::{DivisionMode, OverflowPolicy};
+use crate::cell::{Cell, DoubleCell};
+use crate::errors::ForthError;
+use crate::stack::Stack;
+
+/// Operación primitiva con números dobles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DoubleOp {
+    /// `D+`
+    DoubleAdd,
+    /// `D-`
+    DoubleSubtract,
+    /// `DNEGATE`
+    DoubleNegate,
+    /// `M*`
+    MixedMultiply,
+    /// `UM*`
+    UnsignedMixedMultiply,
+    /// `UM/MOD`
+    UnsignedMixedDivMod,
+    /// `FM/MOD`
+    FlooredDivMod,
+    /// `SM/REM`
+    SymmetricDivRem,
+    /// `*/`
+    Scale,
+    /// `*/MOD`
+    ScaleMod,
+}
+
+/// Aplica una operación con números dobles (de dos celdas) o con resultados intermedios dobles.
+///
+/// Un número doble ocupa dos celdas de la pila, con la más significativa en el tope.
+///
+/// # Operadores soportados
+/// - `D+`: Suma dos números dobles `( d1 d2 -- d3 )`.
+/// - `D-`: Resta dos números dobles `( d1 d2 -- d3 )`.
+/// - `DNEGATE`: Cambia el signo de un número doble `( d -- -d )`.
+/// - `M*`: Multiplica dos celdas con signo y deja el producto doble `( n1 n2 -- d )`.
+/// - `UM*`: Multiplica dos celdas sin signo y deja el producto doble `( u1 u2 -- ud )`.
+/// - `UM/MOD`: Divide un doble sin signo por una celda `( ud u1 -- resto cociente )`.
+/// - `FM/MOD`: Divide un doble por una celda, redondeando hacia menos infinito `( d n -- resto cociente )`.
+/// - `SM/REM`: Divide un doble por una celda, redondeando hacia cero `( d n -- resto cociente )`.
+/// - `*/`: Multiplica y divide con un producto intermedio doble `( n1 n2 n3 -- n1*n2/n3 )`.
+/// - `*/MOD`: Como `*/`, dejando también el resto `( n1 n2 n3 -- resto cociente )`.
+///
+/// Los números dobles dan la vuelta al superar el rango de dos celdas; los cocientes de una celda
+/// se ajustan según la política de desborde.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `operator`: Operación a ejecutar.
+/// - `overflow`: Política a aplicar cuando un cociente no entra en una celda.
+/// - `division`: Redondeo de `*/` y `*/MOD`.
+///
+/// # Retorna
+/// - `Ok(())` si la operación se ejecuta correctamente.
+/// - `Err(ForthError)` con:
+///   - `"stack-underflow"` si la pila no tiene suficientes elementos.
+///   - `"division-by-zero"` si el divisor es cero.
+///   - `"arithmetic-overflow"` si el cociente desborda y la política es `OverflowPolicy::Error`.
+pub fn apply_double_operation(
+    stack: &mut Stack,
+    operator: DoubleOp,
+    overflow: OverflowPolicy,
+    division: DivisionMode,
+) -> Result<(), ForthError> {
+    match operator {
+        DoubleOp::DoubleAdd => double_sum(stack),
+        DoubleOp::DoubleSubtract => double_subtraction(stack),
+        DoubleOp::DoubleNegate => double_negate(stack),
+        DoubleOp::MixedMultiply => mixed_multiplication(stack),
+        DoubleOp::UnsignedMixedMultiply => unsigned_mixed_multiplication(stack),
+        DoubleOp::UnsignedMixedDivMod => unsigned_mixed_division(stack, overflow),
+        DoubleOp::FlooredDivMod => mixed_division(stack, overflow, DivisionMode::Floored),
+        DoubleOp::SymmetricDivRem => mixed_division(stack, overflow, DivisionMode::Symmetric),
+        DoubleOp::Scale => scale(stack, overflow, division, false),
+        DoubleOp::ScaleMod => scale(stack, overflow, division, true),
+    }
+}
+
+/// Extrae los dos números dobles superiores de la pila.
+///
+/// # Retorna
+/// - `Ok((DoubleCell, DoubleCell))` con el número del tope y el que estaba debajo.
+/// - `Err(ForthError)` si la pila no tiene cuatro elementos.
+fn pop_double_operands(stack: &mut Stack) -> Result<(DoubleCell, DoubleCell), ForthError> {
+    if stack.len() < 4 {
+        return Err(ForthError::StackUnderflow);
+    }
+
+    match (stack.pop_double(), stack.pop_double()) {
+        (Some(a), Some(b)) => Ok((a, b)),
+        _ => Err(ForthError::StackUnderflow),
+    }
+}
+
+/// Extrae los dos elementos superiores de la pila.
+///
+/// # Retorna
+/// - `Ok((Cell, Cell))` con el valor del tope y el que estaba debajo.
+/// - `Err(ForthError)` si la pila no tiene suficientes elementos.
+fn pop_operands(stack: &mut Stack) -> Result<(Cell, Cell), ForthError> {
+    match (stack.pop(), stack.pop()) {
+        (Some(a), Some(b)) => Ok((a, b)),
+        _ => Err(ForthError::StackUnderflow),
+    }
+}
+
+/// Suma los dos números dobles superiores de la pila `( d1 d2 -- d1+d2 )`.
+fn double_sum(stack: &mut Stack) -> Result<(), ForthError> {
+    let (a, b) = pop_double_operands(stack)?;
+    let width = stack.width();
+    stack.push_double(width.wrap_double(b.wrapping_add(a)))
+}
+
+/// Resta los dos números dobles superiores de la pila `( d1 d2 -- d1-d2 )`.
+fn double_subtraction(stack: &mut Stack) -> Result<(), ForthError> {
+    let (a, b) = pop_double_operands(stack)?;
+    let width = stack.width();
+    stack.push_double(width.wrap_double(b.wrapping_sub(a)))
+}
+
+/// Cambia el signo del número doble del tope de la pila `( d -- -d )`.
+fn double_negate(stack: &mut Stack) -> Result<(), ForthError> {
+    let value = stack.pop_double().ok_or(ForthError::StackUnderflow)?;
+    let width = stack.width();
+    stack.push_double(width.wrap_double(value.wrapping_neg()))
+}
+
+/// Multiplica dos celdas con signo y apila el producto como número doble `( n1 n2 -- d )`.
+fn mixed_multiplication(stack: &mut Stack) -> Result<(), ForthError> {
+    let (a, b) = pop_operands(stack)?;
+    stack.push_double(DoubleCell::from(b) * DoubleCell::from(a))
+}
+
+/// Multiplica dos celdas sin signo y apila el producto como número doble sin signo `( u1 u2 -- ud )`.
+fn unsigned_mixed_multiplication(stack: &mut Stack) -> Result<(), ForthError> {
+    let (a, b) = pop_operands(stack)?;
+    let width = stack.width();
+    let product = width.unsigned(b) * width.unsigned(a);
+    stack.push_double(product as DoubleCell)
+}
+
+/// Divide un número doble sin signo por una celda sin signo `( ud u1 -- resto cociente )`.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `overflow`: Política a aplicar cuando el cociente no entra en una celda.
+fn unsigned_mixed_division(stack: &mut Stack, overflow: OverflowPolicy) -> Result<(), ForthError> {
+    if stack.len() < 3 {
+        return Err(ForthError::StackUnderflow);
+    }
+
+    let width = stack.width();
+    let divisor = stack.pop().map(|value| width.unsigned(value));
+    let dividend = stack.pop_double();
+
+    match (divisor, dividend) {
+        (Some(0), Some(_)) => Err(ForthError::DivisionByZero),
+        (Some(divisor), Some(dividend)) => {
+            let dividend = width.unsigned_double(dividend);
+            let quotient = overflow.apply_unsigned(dividend / divisor, width)?;
+            stack.push(width.wrap((dividend % divisor) as DoubleCell))?;
+            stack.push(quotient)
+        }
+        _ => Err(ForthError::StackUnderflow),
+    }
+}
+
+/// Divide un número doble por una celda con signo `( d n -- resto cociente )`.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `overflow`: Política a aplicar cuando el cociente no entra en una celda.
+/// - `division`: Redondeo del cociente y signo del resto.
+fn mixed_division(
+    stack: &mut Stack,
+    overflow: OverflowPolicy,
+    division: DivisionMode,
+) -> Result<(), ForthError> {
+    if stack.len() < 3 {
+        return Err(ForthError::StackUnderflow);
+    }
+
+    let divisor = stack.pop();
+    let dividend = stack.pop_double();
+
+    match (divisor, dividend) {
+        (Some(0), Some(_)) => Err(ForthError::DivisionByZero),
+        (Some(divisor), Some(dividend)) => {
+            let (remainder, quotient) = division.divide(dividend, DoubleCell::from(divisor));
+            let quotient = overflow.apply(quotient, stack.width())?;
+            stack.push(remainder as Cell)?;
+            stack.push(quotient)
+        }
+        _ => Err(ForthError::StackUnderflow),
+    }
+}
+
+/// Multiplica las dos celdas debajo del tope y divide el producto doble por el tope
+/// `( n1 n2 n3 -- n4 )`, o `( n1 n2 n3 -- resto cociente )` si se pide también el resto.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `overflow`: Política a aplicar cuando el cociente no entra en una celda.
+/// - `division`: Redondeo del cociente y signo del resto.
+/// - `with_remainder`: Si es `true`, se apila el resto debajo del cociente (`*/MOD`).
+fn scale(
+    stack: &mut Stack,
+    overflow: OverflowPolicy,
+    division: DivisionMode,
+    with_remainder: bool,
+) -> Result<(), ForthError> {
+    if stack.len() < 3 {
+        return Err(ForthError::StackUnderflow);
+    }
+
+    let (divisor, n2) = pop_operands(stack)?;
+    let n1 = stack.pop().ok_or(ForthError::StackUnderflow)?;
+    if divisor == 0 {
+        return Err(ForthError::DivisionByZero);
+    }
+
+    let product = DoubleCell::from(n1) * DoubleCell::from(n2);
+    let (remainder, quotient) = division.divide(product, DoubleCell::from(divisor));
+    let quotient = overflow.apply(quotient, stack.width())?;
+
+    if with_remainder {
+        stack.push(remainder as Cell)?;
+    }
+    stack.push(quotient)
+}
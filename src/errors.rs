@@ -0,0 +1,268 @@
+use crate::tokenizer::Source;
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// Errores que puede producir el intérprete al compilar o ejecutar un programa.
+///
+/// El mensaje de cada error (su implementación de `Display`) es el texto que el intérprete
+/// muestra al usuario.
+#[derive(Debug, PartialEq)]
+pub enum ForthError {
+    /// La pila de datos no tiene suficientes elementos.
+    StackUnderflow,
+    /// La pila de datos está llena.
+    StackOverflow,
+    /// La pila de retorno no tiene suficientes elementos.
+    ReturnStackUnderflow,
+    /// La pila de retorno está llena.
+    ReturnStackOverflow,
+    /// Se intentó dividir por cero.
+    DivisionByZero,
+    /// El resultado de una operación aritmética no entra en una celda.
+    ArithmeticOverflow,
+    /// La palabra no es una primitiva, una palabra definida ni un número.
+    UnknownWord(String),
+    /// Se intentó definir una palabra cuyo nombre es un número.
+    InvalidWord,
+    /// La dirección no pertenece al espacio de datos.
+    InvalidMemoryAddress,
+    /// No queda lugar en el espacio de datos.
+    DataSpaceOverflow,
+    /// El número en conversión (`<# ... #>`) no entra en el área de conversión.
+    PicturedOutputOverflow,
+    /// Falta el nombre de la palabra a definir o sobre la que se opera.
+    MissingWordName,
+    /// La definición no termina con `;`.
+    UnterminatedDefinition,
+    /// La cadena de texto no termina con `"`.
+    UnterminatedString,
+    /// El comentario no termina con `)`.
+    UnterminatedComment,
+    /// La estructura de control no fue cerrada; contiene las palabras de cierre esperadas.
+    UnterminatedControl(&'static str),
+    /// Una palabra cierra la definición o una estructura de control mientras otra sigue abierta;
+    /// contiene la palabra y las palabras de cierre esperadas.
+    MismatchedControl(String, &'static str),
+    /// La palabra de cierre de una estructura de control no tiene su palabra de apertura.
+    UnmatchedControl(String),
+    /// `TO` se aplicó a una palabra que no fue definida con `VALUE`.
+    NotAValue(String),
+    /// La palabra solo puede usarse dentro de un ciclo `DO`.
+    OutsideLoop(&'static str),
+    /// Se pidió el índice de un ciclo que no está en ejecución.
+    LoopIndexOutsideLoop,
+    /// El valor de un argumento de la línea de comandos no es válido.
+    InvalidArgument(String),
+    /// Falla de entrada/salida al leer el programa o al guardar la pila.
+    Io(IoError),
+    /// Error ocurrido en una posición del programa.
+    At(Box<SourceError>),
+}
+
+impl ForthError {
+    /// Asocia el error al token del programa que lo produjo.
+    ///
+    /// Si el error ya tiene una posición, se conserva la original, que es la más precisa.
+    ///
+    /// # Parámetros
+    /// - `source`: Token del programa y su posición.
+    pub fn at(self, source: &Source) -> ForthError {
+        match self {
+            ForthError::At(_) => self,
+            error => ForthError::At(Box::new(SourceError {
+                error,
+                file: None,
+                source: source.clone(),
+                trace: Vec::new(),
+                suggestions: Vec::new(),
+            })),
+        }
+    }
+
+    /// Agrega una palabra a la cadena de llamadas en ejecución cuando ocurrió el error.
+    ///
+    /// Las palabras se agregan desde la más interna hacia la más externa.
+    ///
+    /// # Parámetros
+    /// - `word`: Nombre de la palabra que estaba en ejecución.
+    pub fn in_word(mut self, word: &str) -> ForthError {
+        if let ForthError::At(located) = &mut self {
+            located.trace.push(word.to_string());
+        }
+        self
+    }
+
+    /// Asocia el error al archivo del programa, si tiene una posición.
+    ///
+    /// # Parámetros
+    /// - `file`: Nombre del archivo.
+    pub fn in_file(mut self, file: &str) -> ForthError {
+        if let ForthError::At(located) = &mut self {
+            located.file = Some(file.to_string());
+        }
+        self
+    }
+
+    /// Agrega las palabras conocidas que se sugieren en lugar de la palabra desconocida, si el
+    /// error tiene una posición.
+    ///
+    /// # Parámetros
+    /// - `suggestions`: Nombres de las palabras sugeridas.
+    pub fn with_suggestions(mut self, suggestions: Vec<String>) -> ForthError {
+        if let ForthError::At(located) = &mut self {
+            located.suggestions = suggestions;
+        }
+        self
+    }
+
+    /// Obtiene el error original, sin la información de su posición.
+    pub fn root(&self) -> &ForthError {
+        match self {
+            ForthError::At(located) => &located.error,
+            error => error,
+        }
+    }
+
+    /// Indica si el error se debe a que la entrada terminó dentro de una definición, una
+    /// estructura de control, una cadena o un comentario, que podrían completarse con más texto.
+    pub fn is_incomplete(&self) -> bool {
+        matches!(
+            self.root(),
+            ForthError::UnterminatedDefinition
+                | ForthError::UnterminatedControl(_)
+                | ForthError::UnterminatedString
+                | ForthError::UnterminatedComment
+        )
+    }
+
+    /// Convierte el error en el error original, descartando la información de su posición.
+    pub fn into_root(self) -> ForthError {
+        match self {
+            ForthError::At(located) => located.error,
+            error => error,
+        }
+    }
+}
+
+/// Error junto con la posición del token que lo produjo y las palabras que estaban en ejecución.
+#[derive(Debug, PartialEq)]
+pub struct SourceError {
+    pub error: ForthError,
+    pub file: Option<String>,
+    pub source: Source,
+    pub trace: Vec<String>,
+    /// Palabras conocidas parecidas a la palabra desconocida que produjo el error.
+    pub suggestions: Vec<String>,
+}
+
+impl fmt::Display for SourceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at ", self.error)?;
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file)?;
+        }
+        write!(f, "{}", self.source.location)?;
+        if !self.trace.is_empty() {
+            write!(f, " in {}", self.trace.join(" <- "))?;
+        }
+        if !self.suggestions.is_empty() {
+            write!(f, " (did you mean {}?)", self.suggestions.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Error de entrada/salida junto con la descripción de la operación que lo produjo.
+#[derive(Debug)]
+pub struct IoError {
+    context: String,
+    source: io::Error,
+}
+
+impl IoError {
+    /// Crea un error de entrada/salida.
+    ///
+    /// # Parámetros
+    /// - `context`: Descripción de la operación que falló.
+    /// - `source`: Error original.
+    pub fn new(context: String, source: io::Error) -> Self {
+        IoError { context, source }
+    }
+
+    /// Obtiene el tipo del error original.
+    pub fn kind(&self) -> io::ErrorKind {
+        self.source.kind()
+    }
+}
+
+impl PartialEq for IoError {
+    fn eq(&self, other: &Self) -> bool {
+        self.context == other.context && self.kind() == other.kind()
+    }
+}
+
+impl fmt::Display for IoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.context, self.source)
+    }
+}
+
+impl fmt::Display for ForthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ForthError::StackUnderflow => write!(f, "stack-underflow"),
+            ForthError::StackOverflow => write!(f, "stack-overflow"),
+            ForthError::ReturnStackUnderflow => write!(f, "return-stack-underflow"),
+            ForthError::ReturnStackOverflow => write!(f, "return-stack-overflow"),
+            ForthError::DivisionByZero => write!(f, "division-by-zero"),
+            ForthError::ArithmeticOverflow => write!(f, "arithmetic-overflow"),
+            ForthError::UnknownWord(_) => write!(f, "?"),
+            ForthError::InvalidWord => write!(f, "invalid-word"),
+            ForthError::InvalidMemoryAddress => write!(f, "invalid-memory-address"),
+            ForthError::DataSpaceOverflow => write!(f, "data-space-overflow"),
+            ForthError::PicturedOutputOverflow => write!(f, "pictured-output-overflow"),
+            ForthError::MissingWordName => {
+                write!(f, "Error: Se esperaba un nombre para la palabra")
+            }
+            ForthError::UnterminatedDefinition => {
+                write!(f, "Error: Se esperaba ';' al final de la definición")
+            }
+            ForthError::UnterminatedString => write!(f, "Error: cadena de texto sin comilla final"),
+            ForthError::UnterminatedComment => {
+                write!(f, "Error: comentario sin paréntesis final")
+            }
+            ForthError::UnterminatedControl(closers) => {
+                write!(f, "Error: Falta {} en la estructura de control", closers)
+            }
+            ForthError::MismatchedControl(word, closers) => {
+                write!(
+                    f,
+                    "Error: '{}' antes de {} en la estructura de control",
+                    word, closers
+                )
+            }
+            ForthError::UnmatchedControl(word) => {
+                write!(f, "Error: '{}' sin estructura de control abierta", word)
+            }
+            ForthError::NotAValue(word) => write!(f, "Error: '{}' no es un VALUE", word),
+            ForthError::OutsideLoop(word) => write!(f, "Error: '{}' fuera de un 'DO'", word),
+            ForthError::LoopIndexOutsideLoop => {
+                write!(f, "Error: Índice de ciclo fuera de un 'DO'")
+            }
+            ForthError::InvalidArgument(arg) => write!(f, "Error: Argumento inválido '{}'", arg),
+            ForthError::Io(error) => write!(f, "{}", error),
+            ForthError::At(located) => write!(f, "{}", located),
+        }
+    }
+}
+
+impl Error for ForthError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ForthError::Io(error) => Some(&error.source),
+            ForthError::At(located) => Some(&located.error),
+            _ => None,
+        }
+    }
+}
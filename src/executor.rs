@@ -0,0 +1,175 @@
+use crate::arithmetic_operations::apply_arithmetic_operation;
+use crate::boolean_operations::apply_boolean_operation;
+use crate::compiler::{Code, Instruction, Primitive};
+use crate::double_operations::apply_double_operation;
+use crate::errors::ForthError;
+use crate::forth_basic_operations::apply_forth_operation;
+use crate::input_operations::apply_input_operation;
+use crate::interpreter_state::InterpreterState;
+use crate::memory_operations::{apply_memory_operation, define_data_word, store_value};
+use crate::output_operations::{apply_output_operation, print_string};
+use crate::pictured_output_operations::apply_pictured_output_operation;
+use crate::return_stack_operations::apply_return_stack_operation;
+use crate::stack::Stack;
+use crate::string_operations::apply_string_operation;
+use crate::tokenizer::Tokenizer;
+use crate::word_definitions::{apply_defining_operation, does, parse_word_name};
+use std::sync::Arc;
+
+/// Ejecuta una lista de instrucciones compiladas.
+///
+/// Al terminar, se descartan los ciclos que hayan quedado abiertos por la lista ejecutada,
+/// tanto si la ejecución fue exitosa como si se produjo un error.
+///
+/// Los errores se asocian al token del programa de la instrucción que los produjo y a la cadena
+/// de palabras definidas por el usuario que estaban en ejecución.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `code`: Instrucciones a ejecutar, con el token del que proviene cada una.
+/// - `state`: Estado del intérprete.
+/// - `tokens`: Iterador de los tokens restantes de la entrada, de donde las palabras definidoras
+///   ejecutadas dentro de una definición toman el nombre de la palabra a crear.
+///
+/// # Retornos
+/// Devuelve `Ok(())` si todas las instrucciones se ejecutaron correctamente, o el primer error producido.
+pub fn execute_instructions(
+    stack: &mut Stack,
+    code: &Code,
+    state: &mut InterpreterState,
+    tokens: &mut Tokenizer,
+) -> Result<(), ForthError> {
+    let loop_depth = state.loops.len();
+    let result = run(stack, code, state, tokens);
+    state.loops.truncate(loop_depth);
+    result
+}
+
+/// Recorre las instrucciones siguiendo los saltos hasta llegar al final de la lista.
+fn run(
+    stack: &mut Stack,
+    code: &Code,
+    state: &mut InterpreterState,
+    tokens: &mut Tokenizer,
+) -> Result<(), ForthError> {
+    let mut pc = 0;
+
+    while let Some(instruction) = code.instructions.get(pc) {
+        match execute_instruction(stack, instruction, state, tokens) {
+            Ok(Some(target)) => pc = target,
+            Ok(None) => pc += 1,
+            Err(error) => {
+                return Err(match code.sources.get(pc) {
+                    Some(source) => error.at(source),
+                    None => error,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Ejecuta una instrucción.
+///
+/// # Retornos
+/// Devuelve `Ok(Some(posición))` si la ejecución debe continuar en otra posición, `Ok(None)` si
+/// continúa en la instrucción siguiente, o el error producido.
+fn execute_instruction(
+    stack: &mut Stack,
+    instruction: &Instruction,
+    state: &mut InterpreterState,
+    tokens: &mut Tokenizer,
+) -> Result<Option<usize>, ForthError> {
+    match instruction {
+        Instruction::Literal(value) => stack.push(*value)?,
+        Instruction::Primitive(primitive) => execute_primitive(stack, *primitive, state)?,
+        Instruction::Call(xt) => {
+            let definition = state
+                .word_map
+                .definition(*xt)
+                .ok_or_else(|| ForthError::UnknownWord(xt.to_string()))?;
+            let body = Arc::clone(&definition.code);
+            let name = definition.name.clone();
+
+            if let Err(error) = execute_instructions(stack, &body, state, tokens) {
+                return Err(match name {
+                    Some(name) => error.in_word(&name),
+                    None => error,
+                });
+            }
+        }
+        Instruction::Branch(target) => return Ok(Some(*target)),
+        Instruction::BranchIfZero(target) => {
+            if stack.pop().ok_or(ForthError::StackUnderflow)? == 0 {
+                return Ok(Some(*target));
+            }
+        }
+        Instruction::Do => state.loops.start(stack)?,
+        Instruction::Loop(start) => {
+            if !state.loops.step(1, stack.width())? {
+                return Ok(Some(*start));
+            }
+        }
+        Instruction::PlusLoop(start) => {
+            let increment = stack.pop().ok_or(ForthError::StackUnderflow)?;
+            if !state.loops.step(increment, stack.width())? {
+                return Ok(Some(*start));
+            }
+        }
+        Instruction::Leave(target) => {
+            state.loops.leave()?;
+            return Ok(Some(*target));
+        }
+        Instruction::LoopIndex(depth) => stack.push(state.loops.index(*depth)?)?,
+        Instruction::Unloop => state.loops.unloop()?,
+        Instruction::PrintString(text) => print_string(text, &mut state.output)?,
+        Instruction::Define(defining_word, name) => {
+            let name = match name {
+                Some(name) => name.clone(),
+                None => parse_word_name(tokens)?,
+            };
+
+            define_data_word(stack, *defining_word, name, state)?;
+        }
+        Instruction::StoreValue(address) => store_value(stack, *address, state)?,
+        Instruction::Does(xt) => does(*xt, state)?,
+    }
+
+    Ok(None)
+}
+
+/// Ejecuta una operación primitiva con el módulo que la implementa.
+fn execute_primitive(
+    stack: &mut Stack,
+    primitive: Primitive,
+    state: &mut InterpreterState,
+) -> Result<(), ForthError> {
+    match primitive {
+        Primitive::Arithmetic(operator) => {
+            apply_arithmetic_operation(stack, operator, state.overflow, state.division)
+        }
+        Primitive::Boolean(operator) => apply_boolean_operation(stack, operator, state.logic),
+        Primitive::Forth(operator) => apply_forth_operation(stack, operator, state.legacy_rot),
+        Primitive::Double(operator) => {
+            apply_double_operation(stack, operator, state.overflow, state.division)
+        }
+        Primitive::Input(operator) => apply_input_operation(stack, operator, state),
+        Primitive::Output(operator) => apply_output_operation(
+            stack,
+            operator,
+            &mut state.output,
+            state.data_space.base(),
+            state.spaced_emit,
+        ),
+        Primitive::PicturedOutput(operator) => {
+            apply_pictured_output_operation(stack, operator, state)
+        }
+        Primitive::String(operator) => apply_string_operation(stack, operator, state),
+        Primitive::ReturnStack(operator) => {
+            apply_return_stack_operation(stack, &mut state.return_stack, operator)
+        }
+        Primitive::Memory(operator) => apply_memory_operation(stack, operator, state),
+        Primitive::Defining(operator) => apply_defining_operation(stack, operator, state),
+    }
+}
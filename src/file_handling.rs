@@ -1,4 +1,5 @@
-use crate::stack::Stack;
+use crate::cell::Cell;
+use crate::errors::{ForthError, IoError};
 use std::fs::{self, File};
 use std::io::{self, Write};
 
@@ -12,33 +13,28 @@ const FILE_TO_PERSIST_DATA: &str = "stack.fth";
 /// # Retorna
 ///
 /// - `Ok(String)` con el contenido del archivo si la lectura es exitosa, o
-/// - `Err(String)` con un mensaje de error si ocurre una falla.
-pub fn read_file(filename: String) -> Result<String, String> {
+/// - `Err(ForthError::Io)` con la causa del error si ocurre una falla.
+pub fn read_file(filename: String) -> Result<String, ForthError> {
     match fs::read_to_string(&filename) {
         Ok(content) => Ok(content),
-        Err(error_msg) => Err(format!(
-            "Error al leer el archivo '{}': {}",
-            filename, error_msg
-        )),
+        Err(error) => Err(ForthError::Io(IoError::new(
+            format!("Error al leer el archivo '{}'", filename),
+            error,
+        ))),
     }
 }
 
 /// Guarda el contenido de la pila en un archivo, preservando el orden original.
 ///
 /// # Parámetros
-/// - `stack`: Referencia mutable a la pila de datos.
+/// - `values`: Valores de la pila de datos, desde el fondo hasta el tope.
 ///
 /// # Retorna
 /// - `Ok(())` si la operación de guardado fue exitosa, o `Err(io::Error)` si ocurre un error durante la escritura.
-pub fn save_stack_to_file(stack: &mut Stack) -> Result<(), io::Error> {
+pub fn save_stack_to_file(values: &[Cell]) -> Result<(), io::Error> {
     let mut file = File::create(FILE_TO_PERSIST_DATA)?;
-    let mut temp_vec = Vec::new();
 
-    while let Some(value) = stack.pop() {
-        temp_vec.push(value);
-    }
-
-    for value in temp_vec.iter().rev() {
+    for value in values {
         write!(file, "{} ", value)?;
     }
 
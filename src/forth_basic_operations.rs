@@ -1,5 +1,46 @@
+use crate::cell::Cell;
+use crate::errors::ForthError;
 use crate::stack::Stack;
 
+/// Operación primitiva de manipulación de la pila.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ForthOp {
+    /// `DUP`
+    Dup,
+    /// `DROP`
+    Drop,
+    /// `SWAP`
+    Swap,
+    /// `OVER`
+    Over,
+    /// `ROT`
+    Rot,
+    /// `-ROT`
+    MinusRot,
+    /// `NIP`
+    Nip,
+    /// `TUCK`
+    Tuck,
+    /// `?DUP`
+    QuestionDup,
+    /// `PICK`
+    Pick,
+    /// `ROLL`
+    Roll,
+    /// `DEPTH`
+    Depth,
+    /// `CLEAR`
+    Clear,
+    /// `2DUP`
+    TwoDup,
+    /// `2DROP`
+    TwoDrop,
+    /// `2SWAP`
+    TwoSwap,
+    /// `2OVER`
+    TwoOver,
+}
+
 /// Aplica una operación del conjunto básico de instrucciones Forth sobre la pila.
 ///
 /// # Operadores soportados
@@ -7,23 +48,57 @@ use crate::stack::Stack;
 /// - `DROP`: Elimina el elemento en el tope de la pila.
 /// - `SWAP`: Intercambia los dos elementos superiores de la pila.
 /// - `OVER`: Copia el penúltimo elemento y lo coloca en el tope.
-/// - `ROT`: Rota los elementos de la pila, colocando el elemento de la base en el tope.
+/// - `ROT`: Rota los tres elementos superiores `( a b c -- b c a )`. En modo de compatibilidad,
+///   coloca el elemento de la base de la pila en el tope.
+/// - `-ROT`: Rota los tres elementos superiores en sentido inverso `( a b c -- c a b )`.
+/// - `NIP`: Elimina el penúltimo elemento `( a b -- b )`.
+/// - `TUCK`: Copia el tope debajo del penúltimo elemento `( a b -- b a b )`.
+/// - `?DUP`: Duplica el tope si es distinto de cero `( x -- 0 | x x )`.
+/// - `PICK`: Copia en el tope el elemento a la profundidad indicada `( xu ... x0 u -- xu ... x0 xu )`.
+/// - `ROLL`: Mueve al tope el elemento a la profundidad indicada `( xu ... x0 u -- xu-1 ... x0 xu )`.
+/// - `DEPTH`: Apila la cantidad de elementos que había en la pila `( -- n )`.
+/// - `CLEAR`: Elimina todos los elementos de la pila.
+/// - `2DUP`: Duplica el par de elementos superiores `( a b -- a b a b )`.
+/// - `2DROP`: Elimina el par de elementos superiores `( a b -- )`.
+/// - `2SWAP`: Intercambia los dos pares superiores `( a b c d -- c d a b )`.
+/// - `2OVER`: Copia el segundo par en el tope `( a b c d -- a b c d a b )`.
 ///
 /// # Parámetros
 /// - `stack`: Referencia mutable a la pila de datos.
-/// - `operator`: Operador en formato string que representa la operación Forth a ejecutar.
+/// - `operator`: La operación Forth a ejecutar.
+/// - `legacy_rot`: Si es `true`, `ROT` mueve la base de la pila al tope, como en versiones
+///   anteriores del intérprete.
 ///
 /// # Retorna
 /// - `Ok(())` si la operación se ejecuta correctamente.
-/// - `Err(String)` si el operador no es reconocido.
-pub fn apply_forth_operation(stack: &mut Stack, operator: &str) -> Result<(), String> {
+/// - `Err(ForthError)` si la pila no tiene suficientes elementos o lugar para el resultado.
+pub fn apply_forth_operation(
+    stack: &mut Stack,
+    operator: ForthOp,
+    legacy_rot: bool,
+) -> Result<(), ForthError> {
     match operator {
-        "DUP" => dup(stack),
-        "DROP" => drop(stack),
-        "SWAP" => swap(stack),
-        "OVER" => over(stack),
-        "ROT" => rot(stack),
-        _ => Err("?".to_string()),
+        ForthOp::Dup => dup(stack),
+        ForthOp::Drop => drop(stack),
+        ForthOp::Swap => swap(stack),
+        ForthOp::Over => over(stack),
+        ForthOp::Rot if legacy_rot => legacy_rotation(stack),
+        ForthOp::Rot => stack.roll(2),
+        ForthOp::MinusRot => stack.unroll(2),
+        ForthOp::Nip => nip(stack),
+        ForthOp::Tuck => tuck(stack),
+        ForthOp::QuestionDup => question_dup(stack),
+        ForthOp::Pick => pick(stack),
+        ForthOp::Roll => roll(stack),
+        ForthOp::Depth => depth(stack),
+        ForthOp::Clear => {
+            stack.clear();
+            Ok(())
+        }
+        ForthOp::TwoDup => two_dup(stack),
+        ForthOp::TwoDrop => two_drop(stack),
+        ForthOp::TwoSwap => two_swap(stack),
+        ForthOp::TwoOver => two_over(stack),
     }
 }
 
@@ -34,15 +109,14 @@ pub fn apply_forth_operation(stack: &mut Stack, operator: &str) -> Result<(), St
 ///
 /// # Retorna
 /// - `Ok(())` si la operación se realiza correctamente.
-/// - `Err(String)` si la pila está vacía (stack underflow).
-fn dup(stack: &mut Stack) -> Result<(), String> {
+/// - `Err(ForthError)` si la pila está vacía (stack underflow).
+fn dup(stack: &mut Stack) -> Result<(), ForthError> {
     match stack.pop() {
         Some(value) => {
-            stack.push(value);
-            stack.push(value);
-            Ok(())
+            stack.push(value)?;
+            stack.push(value)
         }
-        _ => Err("stack-underflow".to_string()),
+        _ => Err(ForthError::StackUnderflow),
     }
 }
 
@@ -53,10 +127,10 @@ fn dup(stack: &mut Stack) -> Result<(), String> {
 ///
 /// # Retorna
 /// - `Ok(())` si la operación se realiza correctamente.
-/// - `Err(String)` si la pila está vacía (stack underflow).
-fn drop(stack: &mut Stack) -> Result<(), String> {
+/// - `Err(ForthError)` si la pila está vacía (stack underflow).
+fn drop(stack: &mut Stack) -> Result<(), ForthError> {
     if stack.pop().is_none() {
-        Err("stack-underflow".to_string())
+        Err(ForthError::StackUnderflow)
     } else {
         Ok(())
     }
@@ -69,15 +143,14 @@ fn drop(stack: &mut Stack) -> Result<(), String> {
 ///
 /// # Retorna
 /// - `Ok(())` si la operación se realiza correctamente.
-/// - `Err(String)` si la pila está vacía (stack underflow).
-fn swap(stack: &mut Stack) -> Result<(), String> {
+/// - `Err(ForthError)` si la pila está vacía (stack underflow).
+fn swap(stack: &mut Stack) -> Result<(), ForthError> {
     match (stack.pop(), stack.pop()) {
         (Some(a), Some(b)) => {
-            stack.push(a);
-            stack.push(b);
-            Ok(())
+            stack.push(a)?;
+            stack.push(b)
         }
-        _ => Err("stack-underflow".to_string()),
+        _ => Err(ForthError::StackUnderflow),
     }
 }
 
@@ -88,44 +161,211 @@ fn swap(stack: &mut Stack) -> Result<(), String> {
 ///
 /// # Retorna
 /// - `Ok(())` si la operación se realiza correctamente.
-/// - `Err(String)` si la pila está vacía (stack underflow).
-fn over(stack: &mut Stack) -> Result<(), String> {
+/// - `Err(ForthError)` si la pila está vacía (stack underflow).
+fn over(stack: &mut Stack) -> Result<(), ForthError> {
     match (stack.pop(), stack.pop()) {
         (Some(a), Some(b)) => {
-            stack.push(b);
-            stack.push(a);
-            stack.push(b);
-            Ok(())
+            stack.push(b)?;
+            stack.push(a)?;
+            stack.push(b)
         }
-        _ => Err("stack-underflow".to_string()),
+        _ => Err(ForthError::StackUnderflow),
     }
 }
 
-/// Mueve el elemento de la base de la pila al tope.
+/// Mueve el elemento de la base de la pila al tope (el `ROT` del modo de compatibilidad).
 ///
 /// # Parámetros
 /// - `stack`: Referencia mutable a la pila de datos.
 ///
 /// # Retorno
 /// - `Ok(())` si la operación se realiza correctamente.
-/// - `Err(String)` si la pila está vacía (stack underflow).
-fn rot(stack: &mut Stack) -> Result<(), String> {
+/// - `Err(ForthError)` si la pila tiene menos de dos elementos (stack underflow).
+fn legacy_rotation(stack: &mut Stack) -> Result<(), ForthError> {
     if stack.len() < 2 {
-        return Err("stack-underflow".to_string());
+        return Err(ForthError::StackUnderflow);
     }
 
-    let mut temp_stack = Vec::new();
-    while let Some(value) = stack.pop() {
-        temp_stack.push(value);
+    stack.roll(stack.len() - 1)
+}
+
+/// Elimina el penúltimo elemento de la pila `( a b -- b )`.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+///
+/// # Retorna
+/// - `Ok(())` si la operación se realiza correctamente.
+/// - `Err(ForthError)` si la pila no tiene dos elementos (stack underflow).
+fn nip(stack: &mut Stack) -> Result<(), ForthError> {
+    stack.roll(1)?;
+    drop(stack)
+}
+
+/// Copia el tope de la pila debajo del penúltimo elemento `( a b -- b a b )`.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+///
+/// # Retorna
+/// - `Ok(())` si la operación se realiza correctamente.
+/// - `Err(ForthError)` si la pila no tiene dos elementos o no hay lugar para la copia.
+fn tuck(stack: &mut Stack) -> Result<(), ForthError> {
+    if stack.len() < 2 {
+        return Err(ForthError::StackUnderflow);
     }
 
-    if let Some(bottom) = temp_stack.pop() {
-        while let Some(value) = temp_stack.pop() {
-            stack.push(value);
+    dup(stack)?;
+    stack.unroll(2)
+}
+
+/// Duplica el elemento en la cima de la pila si es distinto de cero `( x -- 0 | x x )`.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+///
+/// # Retorna
+/// - `Ok(())` si la operación se realiza correctamente.
+/// - `Err(ForthError)` si la pila está vacía o no hay lugar para la copia.
+fn question_dup(stack: &mut Stack) -> Result<(), ForthError> {
+    match stack.pick(0) {
+        Some(0) => Ok(()),
+        Some(value) => stack.push(value),
+        None => Err(ForthError::StackUnderflow),
+    }
+}
+
+/// Extrae del tope de la pila una profundidad para `PICK` o `ROLL`.
+///
+/// # Retorna
+/// - `Ok(usize)` con la profundidad, donde `0` es el elemento que queda en el tope.
+/// - `Err(ForthError)` si la pila está vacía, la profundidad es negativa o la pila no tiene
+///   tantos elementos (stack underflow).
+fn pop_depth(stack: &mut Stack) -> Result<usize, ForthError> {
+    let depth = stack.pick(0).ok_or(ForthError::StackUnderflow)?;
+    match usize::try_from(depth) {
+        Ok(depth) if depth < stack.len() - 1 => {
+            stack.pop();
+            Ok(depth)
+        }
+        _ => Err(ForthError::StackUnderflow),
+    }
+}
+
+/// Copia en el tope el elemento a la profundidad indicada por el tope
+/// `( xu ... x0 u -- xu ... x0 xu )`. `0 PICK` equivale a `DUP` y `1 PICK` a `OVER`.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+///
+/// # Retorna
+/// - `Ok(())` si la operación se realiza correctamente.
+/// - `Err(ForthError)` si la pila no tiene suficientes elementos (stack underflow).
+fn pick(stack: &mut Stack) -> Result<(), ForthError> {
+    let depth = pop_depth(stack)?;
+    match stack.pick(depth) {
+        Some(value) => stack.push(value),
+        None => Err(ForthError::StackUnderflow),
+    }
+}
+
+/// Mueve al tope el elemento a la profundidad indicada por el tope
+/// `( xu ... x0 u -- xu-1 ... x0 xu )`. `1 ROLL` equivale a `SWAP` y `2 ROLL` a `ROT`.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+///
+/// # Retorna
+/// - `Ok(())` si la operación se realiza correctamente.
+/// - `Err(ForthError)` si la pila no tiene suficientes elementos (stack underflow).
+fn roll(stack: &mut Stack) -> Result<(), ForthError> {
+    let depth = pop_depth(stack)?;
+    stack.roll(depth)
+}
+
+/// Apila la cantidad de elementos que había en la pila `( -- n )`.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+///
+/// # Retorna
+/// - `Ok(())` si la operación se realiza correctamente.
+/// - `Err(ForthError)` si la pila está llena (stack overflow).
+fn depth(stack: &mut Stack) -> Result<(), ForthError> {
+    stack.push(stack.len() as Cell)
+}
+
+/// Copia en el tope el par de elementos que está a la profundidad indicada, sin modificar la
+/// pila si no tiene tantos elementos.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `depth`: Profundidad del elemento más profundo del par, donde `1` es el par del tope.
+///
+/// # Retorna
+/// - `Ok(())` si la operación se realiza correctamente.
+/// - `Err(ForthError)` si la pila no tiene suficientes elementos o no hay lugar para la copia.
+fn copy_pair(stack: &mut Stack, depth: usize) -> Result<(), ForthError> {
+    match (stack.pick(depth), stack.pick(depth - 1)) {
+        (Some(a), Some(b)) => {
+            stack.push(a)?;
+            stack.push(b)
         }
+        _ => Err(ForthError::StackUnderflow),
+    }
+}
 
-        stack.push(bottom);
+/// Duplica el par de elementos superiores de la pila `( a b -- a b a b )`.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+///
+/// # Retorna
+/// - `Ok(())` si la operación se realiza correctamente.
+/// - `Err(ForthError)` si la pila no tiene dos elementos o no hay lugar para la copia.
+fn two_dup(stack: &mut Stack) -> Result<(), ForthError> {
+    copy_pair(stack, 1)
+}
+
+/// Elimina el par de elementos superiores de la pila `( a b -- )`.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+///
+/// # Retorna
+/// - `Ok(())` si la operación se realiza correctamente.
+/// - `Err(ForthError)` si la pila no tiene dos elementos (stack underflow).
+fn two_drop(stack: &mut Stack) -> Result<(), ForthError> {
+    if stack.len() < 2 {
+        return Err(ForthError::StackUnderflow);
     }
 
+    stack.pop();
+    stack.pop();
     Ok(())
 }
+
+/// Intercambia los dos pares de elementos superiores de la pila `( a b c d -- c d a b )`.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+///
+/// # Retorna
+/// - `Ok(())` si la operación se realiza correctamente.
+/// - `Err(ForthError)` si la pila no tiene cuatro elementos (stack underflow).
+fn two_swap(stack: &mut Stack) -> Result<(), ForthError> {
+    stack.roll(3)?;
+    stack.roll(3)
+}
+
+/// Copia el segundo par de elementos de la pila en el tope `( a b c d -- a b c d a b )`.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+///
+/// # Retorna
+/// - `Ok(())` si la operación se realiza correctamente.
+/// - `Err(ForthError)` si la pila no tiene cuatro elementos o no hay lugar para la copia.
+fn two_over(stack: &mut Stack) -> Result<(), ForthError> {
+    copy_pair(stack, 3)
+}
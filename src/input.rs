@@ -0,0 +1,436 @@
+use crate::errors::{ForthError, IoError};
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{self, BufRead, Cursor, Read, Seek};
+use std::sync::{Arc, Condvar, LazyLock, Mutex, MutexGuard, Once, PoisonError};
+use std::thread;
+
+/// Origen de los caracteres que leen los programas, que además permite consultar sin esperar si
+/// hay caracteres disponibles, como necesita `KEY?`.
+///
+/// Está implementado para [`InputBuffer`], los slices de bytes, [`Cursor`], [`File`] y [`Box`].
+/// Cualquier otro [`Read`] puede usarse con [`BlockingInput`], que no puede consultar sin esperar.
+pub trait InputReader: Read {
+    /// Indica, sin esperar, si la próxima lectura devuelve un caracter de inmediato.
+    ///
+    /// # Retorna
+    /// - `Ok(true)` si hay un caracter disponible.
+    /// - `Ok(false)` si todavía no llegó ninguno o si la entrada terminó.
+    /// - `Err(io::Error)` si falla la consulta.
+    fn is_ready(&mut self) -> io::Result<bool>;
+}
+
+impl InputReader for &[u8] {
+    fn is_ready(&mut self) -> io::Result<bool> {
+        Ok(!self.is_empty())
+    }
+}
+
+impl<T: AsRef<[u8]>> InputReader for Cursor<T> {
+    fn is_ready(&mut self) -> io::Result<bool> {
+        Ok(self.position() < self.get_ref().as_ref().len() as u64)
+    }
+}
+
+impl<R: InputReader + ?Sized> InputReader for Box<R> {
+    fn is_ready(&mut self) -> io::Result<bool> {
+        (**self).is_ready()
+    }
+}
+
+/// Un archivo está listo mientras no se haya leído hasta su tamaño actual. Para archivos
+/// especiales, como los pipes con nombre, que informan tamaño cero, se considera que no hay
+/// caracteres disponibles.
+impl InputReader for File {
+    fn is_ready(&mut self) -> io::Result<bool> {
+        Ok(self.stream_position()? < self.metadata()?.len())
+    }
+}
+
+/// Entrada de donde leen los programas en ejecución con `KEY`, `XKEY`, `KEY?`, `ACCEPT` y `EXPECT`.
+/// Debe poder enviarse a otro hilo junto con el intérprete.
+pub type Input = Box<dyn InputReader + Send>;
+
+/// Crea la entrada por defecto del intérprete: la entrada estándar, leída solo cuando un programa
+/// la usa.
+///
+/// Es una [`BlockingInput`] sobre la entrada estándar, por lo que `KEY?` espera hasta que llegue
+/// un caracter o termine la entrada. No inicia ningún hilo, de modo que un programa que incluya el
+/// intérprete conserva la entrada estándar mientras sus programas no la lean.
+pub fn stdin_input() -> Input {
+    Box::new(BlockingInput::new(io::stdin()))
+}
+
+/// Crea una entrada estándar compartida con el intérprete interactivo, que `KEY?` puede consultar
+/// sin esperar.
+///
+/// La entrada estándar la lee un hilo propio desde la primera lectura hasta que termina, guardando
+/// los caracteres a medida que llegan. En una terminal, los caracteres llegan recién al presionar
+/// Enter. El intérprete interactivo lee sus líneas de la misma entrada (ver [`StdinLines`]), por
+/// lo que puede compartirse con los programas que ejecuta. Como el hilo se queda con la entrada
+/// estándar, está pensada para el ejecutable y no para programas que incluyan el intérprete.
+pub fn shared_stdin_input() -> Input {
+    Box::new(StdinInput)
+}
+
+/// Convierte una falla al leer la entrada en un error del intérprete.
+///
+/// # Parámetros
+/// - `error`: Error de entrada/salida producido al leer.
+pub fn input_error(error: io::Error) -> ForthError {
+    ForthError::Io(IoError::new("Error al leer la entrada".to_string(), error))
+}
+
+/// Adapta un origen que no permite consultar sin esperar, como un socket o una entrada propia.
+///
+/// `KEY?` lee un caracter por adelantado, por lo que espera hasta que llegue uno o termine la
+/// entrada.
+pub struct BlockingInput<R> {
+    reader: R,
+    pending: Option<u8>,
+}
+
+impl<R: Read> BlockingInput<R> {
+    /// Crea una entrada que lee del origen indicado.
+    ///
+    /// # Parámetros
+    /// - `reader`: Origen de los caracteres.
+    pub fn new(reader: R) -> Self {
+        BlockingInput {
+            reader,
+            pending: None,
+        }
+    }
+}
+
+impl<R: Read> Read for BlockingInput<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match (self.pending.take(), buf.first_mut()) {
+            (Some(byte), Some(first)) => {
+                *first = byte;
+                Ok(1)
+            }
+            (pending, _) => {
+                self.pending = pending;
+                self.reader.read(buf)
+            }
+        }
+    }
+}
+
+impl<R: Read> InputReader for BlockingInput<R> {
+    fn is_ready(&mut self) -> io::Result<bool> {
+        let mut byte = [0];
+        while self.pending.is_none() {
+            match self.reader.read(&mut byte) {
+                Ok(0) => return Ok(false),
+                Ok(_) => self.pending = Some(byte[0]),
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// Entrada del intérprete junto con el caracter que haya quedado sin usar al decodificar UTF-8.
+pub struct InputSource {
+    reader: Input,
+    pending: Option<u8>,
+}
+
+impl InputSource {
+    /// Crea una entrada que lee del origen indicado.
+    ///
+    /// # Parámetros
+    /// - `reader`: Origen de los caracteres.
+    pub fn new(reader: Input) -> Self {
+        InputSource {
+            reader,
+            pending: None,
+        }
+    }
+
+    /// Lee el próximo caracter de la entrada, esperando a que esté disponible.
+    ///
+    /// # Retorna
+    /// - `Ok(Some(u8))` con el caracter leído.
+    /// - `Ok(None)` si la entrada terminó.
+    /// - `Err(ForthError::Io)` si falla la lectura.
+    pub fn next_byte(&mut self) -> Result<Option<u8>, ForthError> {
+        if let Some(byte) = self.pending.take() {
+            return Ok(Some(byte));
+        }
+
+        let mut byte = [0];
+        loop {
+            match self.reader.read(&mut byte) {
+                Ok(0) => return Ok(None),
+                Ok(_) => return Ok(Some(byte[0])),
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(input_error(error)),
+            }
+        }
+    }
+
+    /// Lee el próximo caracter Unicode de la entrada, codificado en UTF-8, esperando a que esté
+    /// disponible.
+    ///
+    /// Una secuencia UTF-8 inválida o incompleta se lee como `U+FFFD`; el byte que la interrumpe,
+    /// si lo hay, queda para la próxima lectura.
+    ///
+    /// # Retorna
+    /// - `Ok(Some(char))` con el caracter leído.
+    /// - `Ok(None)` si la entrada terminó.
+    /// - `Err(ForthError::Io)` si falla la lectura.
+    pub fn next_char(&mut self) -> Result<Option<char>, ForthError> {
+        let Some(first) = self.next_byte()? else {
+            return Ok(None);
+        };
+        let length = match first {
+            0x00..=0x7F => 1,
+            0xC0..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            0xF0..=0xF7 => 4,
+            _ => return Ok(Some(char::REPLACEMENT_CHARACTER)),
+        };
+
+        let mut bytes = vec![first];
+        while bytes.len() < length {
+            match self.next_byte()? {
+                Some(byte) if byte & 0xC0 == 0x80 => bytes.push(byte),
+                byte => {
+                    self.pending = byte;
+                    return Ok(Some(char::REPLACEMENT_CHARACTER));
+                }
+            }
+        }
+
+        let char = std::str::from_utf8(&bytes)
+            .ok()
+            .and_then(|text| text.chars().next());
+        Ok(Some(char.unwrap_or(char::REPLACEMENT_CHARACTER)))
+    }
+
+    /// Indica, sin esperar, si hay un caracter para leer.
+    ///
+    /// # Retorna
+    /// - `Ok(true)` si la próxima lectura devuelve un caracter de inmediato.
+    /// - `Ok(false)` si todavía no llegó ninguno o si la entrada terminó.
+    /// - `Err(ForthError::Io)` si falla la consulta.
+    pub fn has_byte(&mut self) -> Result<bool, ForthError> {
+        if self.pending.is_some() {
+            return Ok(true);
+        }
+        self.reader.is_ready().map_err(input_error)
+    }
+}
+
+impl Default for InputSource {
+    fn default() -> Self {
+        InputSource::new(stdin_input())
+    }
+}
+
+/// Buffer en memoria que puede usarse como entrada del intérprete y completarse después.
+///
+/// Las copias del buffer comparten el mismo contenido, de modo que se puede entregar una copia al
+/// intérprete y conservar otra para agregarle texto, incluso desde otro hilo.
+#[derive(Debug, Clone, Default)]
+pub struct InputBuffer {
+    bytes: Arc<Mutex<VecDeque<u8>>>,
+}
+
+impl InputBuffer {
+    /// Crea un buffer vacío.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, VecDeque<u8>> {
+        self.bytes.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Agrega texto al final del buffer, para que lo lean los programas.
+    ///
+    /// # Parámetros
+    /// - `text`: Texto a agregar.
+    pub fn push_str(&self, text: &str) {
+        self.lock().extend(text.bytes());
+    }
+
+    /// Obtiene el texto que todavía no fue leído.
+    pub fn remaining(&self) -> String {
+        let bytes: Vec<u8> = self.lock().iter().copied().collect();
+        String::from_utf8_lossy(&bytes).to_string()
+    }
+}
+
+impl From<&str> for InputBuffer {
+    fn from(text: &str) -> Self {
+        let buffer = InputBuffer::new();
+        buffer.push_str(text);
+        buffer
+    }
+}
+
+impl Read for InputBuffer {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.lock().read(buf)
+    }
+}
+
+impl InputReader for InputBuffer {
+    fn is_ready(&mut self) -> io::Result<bool> {
+        Ok(!self.lock().is_empty())
+    }
+}
+
+/// Caracteres de la entrada estándar que ya llegaron y todavía no fueron leídos.
+#[derive(Default)]
+struct StdinBuffer {
+    bytes: VecDeque<u8>,
+    closed: bool,
+    error: Option<io::Error>,
+}
+
+/// Entrada estándar compartida por el intérprete interactivo y los programas, que la lee un hilo
+/// propio a partir de la primera lectura.
+#[derive(Default)]
+struct SharedStdin {
+    buffer: Mutex<StdinBuffer>,
+    arrived: Condvar,
+}
+
+impl SharedStdin {
+    /// Obtiene la entrada estándar compartida, iniciando el hilo que la lee si es necesario.
+    fn get() -> &'static SharedStdin {
+        static STDIN: LazyLock<SharedStdin> = LazyLock::new(SharedStdin::default);
+        static READER: Once = Once::new();
+
+        READER.call_once(|| {
+            thread::spawn(|| STDIN.read_all());
+        });
+        &STDIN
+    }
+
+    /// Lee la entrada estándar hasta que termina, guardando los caracteres a medida que llegan.
+    fn read_all(&self) {
+        let mut chunk = [0; 1024];
+        loop {
+            let result = io::stdin().read(&mut chunk);
+            let mut buffer = self.lock();
+            match result {
+                Ok(0) => buffer.closed = true,
+                Ok(count) => buffer.bytes.extend(&chunk[..count]),
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => {
+                    buffer.error = Some(error);
+                    buffer.closed = true;
+                }
+            }
+            let closed = buffer.closed;
+            drop(buffer);
+            self.arrived.notify_all();
+            if closed {
+                return;
+            }
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, StdinBuffer> {
+        self.buffer.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Espera hasta que los caracteres recibidos cumplan la condición indicada o la entrada
+    /// termine.
+    ///
+    /// # Parámetros
+    /// - `ready`: Condición que deben cumplir los caracteres recibidos.
+    ///
+    /// # Retorna
+    /// - `Ok(MutexGuard)` con los caracteres recibidos.
+    /// - `Err(io::Error)` si falló la lectura de la entrada estándar.
+    fn wait_until(
+        &self,
+        ready: impl Fn(&VecDeque<u8>) -> bool,
+    ) -> io::Result<MutexGuard<'_, StdinBuffer>> {
+        let mut buffer = self.lock();
+        while !ready(&buffer.bytes) && !buffer.closed {
+            buffer = self
+                .arrived
+                .wait(buffer)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        match buffer.error.take() {
+            Some(error) => Err(error),
+            None => Ok(buffer),
+        }
+    }
+}
+
+/// Entrada estándar compartida, leída por el hilo de [`SharedStdin`].
+struct StdinInput;
+
+impl Read for StdinInput {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut buffer = SharedStdin::get().wait_until(|bytes| !bytes.is_empty())?;
+        buffer.bytes.read(buf)
+    }
+}
+
+impl InputReader for StdinInput {
+    fn is_ready(&mut self) -> io::Result<bool> {
+        let mut buffer = SharedStdin::get().lock();
+        match buffer.error.take() {
+            Some(error) => Err(error),
+            None => Ok(!buffer.bytes.is_empty()),
+        }
+    }
+}
+
+/// Entrada estándar leída de a una línea por vez, sin retenerla entre líneas.
+///
+/// El intérprete interactivo lee sus líneas con este tipo, de modo que los caracteres que siguen
+/// a cada línea quedan disponibles para `KEY` y `ACCEPT` de los programas que se ejecutan.
+#[derive(Debug, Default)]
+pub struct StdinLines {
+    line: Vec<u8>,
+    position: usize,
+}
+
+impl StdinLines {
+    /// Crea un lector de la entrada estándar sin líneas leídas.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Read for StdinLines {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let count = self.fill_buf()?.read(buf)?;
+        self.consume(count);
+        Ok(count)
+    }
+}
+
+impl BufRead for StdinLines {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.position == self.line.len() {
+            self.line.clear();
+            self.position = 0;
+            let mut buffer = SharedStdin::get().wait_until(|bytes| bytes.contains(&b'\n'))?;
+            let end = buffer
+                .bytes
+                .iter()
+                .position(|byte| *byte == b'\n')
+                .map_or(buffer.bytes.len(), |position| position + 1);
+            self.line.extend(buffer.bytes.drain(..end));
+        }
+        Ok(&self.line[self.position..])
+    }
+
+    fn consume(&mut self, amount: usize) {
+        self.position = (self.position + amount).min(self.line.len());
+    }
+}
@@ -0,0 +1,118 @@
+use crate::cell::Cell;
+use crate::errors::ForthError;
+use crate::interpreter_state::InterpreterState;
+use crate::output::output_error;
+use crate::stack::Stack;
+use std::io::Write;
+
+/// Valor que deja `KEY` cuando la entrada terminó.
+const END_OF_INPUT: Cell = -1;
+const FALSE: Cell = 0;
+const TRUE: Cell = -1;
+
+/// Operación primitiva de entrada.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputOp {
+    /// `KEY`
+    Key,
+    /// `XKEY`
+    ExtendedKey,
+    /// `KEY?`
+    KeyReady,
+    /// `ACCEPT`
+    Accept,
+    /// `EXPECT`
+    Expect,
+}
+
+/// Aplica una operación que lee caracteres de la entrada del intérprete.
+///
+/// Antes de esperar la entrada se escribe la salida pendiente, para que el usuario vea los
+/// mensajes que la preceden.
+///
+/// # Operadores soportados
+/// - `KEY`: Lee un caracter y apila su código, o `-1` si la entrada terminó `( -- char )`.
+/// - `XKEY`: Lee un caracter Unicode codificado en UTF-8 y apila su código, o `-1` si la entrada
+///   terminó `( -- xchar )`.
+/// - `KEY?`: Apila verdadero si hay un caracter para leer `( -- flag )`.
+/// - `ACCEPT`: Lee una línea de hasta `n` caracteres en la dirección indicada y apila la cantidad
+///   leída, sin el salto de línea `( addr n -- n2 )`.
+/// - `EXPECT`: Como `ACCEPT`, guardando la cantidad leída en `SPAN` `( addr n -- )`.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `operator`: Operación a ejecutar.
+/// - `state`: Estado del intérprete con la entrada, la salida y el espacio de datos.
+///
+/// # Retorna
+/// - `Ok(())` si la operación se ejecuta correctamente.
+/// - `Err(ForthError)` con:
+///   - `"stack-underflow"` si la pila no tiene suficientes elementos.
+///   - `"invalid-memory-address"` si la línea no entra en el espacio de datos reservado.
+///   - `ForthError::Io` si falla la lectura o la escritura.
+pub fn apply_input_operation(
+    stack: &mut Stack,
+    operator: InputOp,
+    state: &mut InterpreterState,
+) -> Result<(), ForthError> {
+    state.output.flush().map_err(output_error)?;
+
+    match operator {
+        InputOp::Key => {
+            let char = state.input.next_byte()?;
+            stack.push(char.map_or(END_OF_INPUT, Cell::from))
+        }
+        InputOp::ExtendedKey => {
+            let char = state.input.next_char()?;
+            stack.push(char.map_or(END_OF_INPUT, |char| Cell::from(u32::from(char))))
+        }
+        InputOp::KeyReady => {
+            let ready = state.input.has_byte()?;
+            stack.push(if ready { TRUE } else { FALSE })
+        }
+        InputOp::Accept => {
+            let count = accept(stack, state)?;
+            stack.push(count)
+        }
+        InputOp::Expect => {
+            let count = accept(stack, state)?;
+            state.data_space.set_span(count);
+            Ok(())
+        }
+    }
+}
+
+/// Lee caracteres de la entrada hasta un salto de línea, el fin de la entrada o la cantidad
+/// máxima indicada, y los almacena a partir de una dirección `( addr n -- )`.
+///
+/// El salto de línea se consume pero no se almacena.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `state`: Estado del intérprete con la entrada y el espacio de datos.
+///
+/// # Retorna
+/// - `Ok(Cell)` con la cantidad de caracteres almacenados.
+/// - `Err(ForthError)` si la pila no tiene la dirección y la cantidad, la dirección no está
+///   reservada o falla la lectura.
+fn accept(stack: &mut Stack, state: &mut InterpreterState) -> Result<Cell, ForthError> {
+    let (limit, address) = match (stack.pop(), stack.pop()) {
+        (Some(limit), Some(address)) => (limit, address),
+        _ => return Err(ForthError::StackUnderflow),
+    };
+
+    let mut count = 0;
+    while count < limit {
+        match state.input.next_byte()? {
+            Some(b'\n') | None => break,
+            Some(char) => {
+                state
+                    .data_space
+                    .store(address.wrapping_add(count), Cell::from(char))?;
+                count += 1;
+            }
+        }
+    }
+
+    Ok(count)
+}
@@ -0,0 +1,315 @@
+use crate::arithmetic_operations::{DivisionMode, OverflowPolicy};
+use crate::boolean_operations::LogicMode;
+use crate::cell::{Cell, CellWidth};
+use crate::errors::ForthError;
+use crate::file_handling::read_file;
+use crate::input::{Input, InputReader, InputSource};
+use crate::interpreter_state::InterpreterState;
+use crate::output::{Output, output_error, stdout_output};
+use crate::program::{
+    DEFAULT_RETURN_STACK_SIZE, DEFAULT_STACK_SIZE, execute_line, execute_operation,
+};
+use crate::stack::Stack;
+use crate::word_definitions::WordMap;
+use std::io::Write;
+
+/// Intérprete Forth listo para ser utilizado desde otros programas.
+///
+/// Es dueño de la pila de datos y del estado del intérprete (diccionario, pila de retorno,
+/// espacio de datos), que se conservan entre sucesivas llamadas a [`Interpreter::eval`]. Puede
+/// enviarse a otro hilo, por ejemplo para ejecutarlo dentro de un servicio.
+///
+/// # Ejemplo
+///
+/// ```
+/// use forth_interpreter::interpreter::Interpreter;
+///
+/// let mut forth = Interpreter::builder().stack_size(1024).build();
+/// assert!(forth.eval(": SQUARE DUP * ;").is_ok());
+///
+/// assert!(forth.push(7).is_ok());
+/// assert!(forth.eval("SQUARE").is_ok());
+/// assert_eq!(forth.stack(), &[49]);
+/// ```
+pub struct Interpreter {
+    stack: Stack,
+    state: InterpreterState,
+    pending: String,
+}
+
+/// Constructor de un [`Interpreter`] con tamaños y opciones configurables.
+pub struct InterpreterBuilder {
+    stack_size: usize,
+    return_stack_size: usize,
+    input: Option<Input>,
+    output: Option<Output>,
+    overflow: OverflowPolicy,
+    division: DivisionMode,
+    logic: LogicMode,
+    legacy_rot: bool,
+    spaced_emit: bool,
+    cell_width: CellWidth,
+}
+
+impl Default for InterpreterBuilder {
+    fn default() -> Self {
+        InterpreterBuilder {
+            stack_size: DEFAULT_STACK_SIZE,
+            return_stack_size: DEFAULT_RETURN_STACK_SIZE,
+            input: None,
+            output: None,
+            overflow: OverflowPolicy::default(),
+            division: DivisionMode::default(),
+            logic: LogicMode::default(),
+            legacy_rot: false,
+            spaced_emit: false,
+            cell_width: CellWidth::default(),
+        }
+    }
+}
+
+impl InterpreterBuilder {
+    /// Define el tamaño máximo de la pila de datos en bytes.
+    ///
+    /// # Parámetros
+    /// - `bytes`: Tamaño de la pila en bytes.
+    pub fn stack_size(mut self, bytes: usize) -> Self {
+        self.stack_size = bytes;
+        self
+    }
+
+    /// Define el tamaño máximo de la pila de retorno en bytes.
+    ///
+    /// # Parámetros
+    /// - `bytes`: Tamaño de la pila de retorno en bytes.
+    pub fn return_stack_size(mut self, bytes: usize) -> Self {
+        self.return_stack_size = bytes;
+        self
+    }
+
+    /// Define la entrada de donde leen `KEY`, `XKEY`, `KEY?`, `ACCEPT` y `EXPECT`. Por defecto se
+    /// usa la entrada estándar, cuyo `KEY?` espera a que llegue un caracter (ver
+    /// [`crate::input::stdin_input`]).
+    ///
+    /// # Parámetros
+    /// - `input`: Origen de la entrada, por ejemplo un archivo o un [`crate::input::InputBuffer`].
+    ///   Otros orígenes pueden usarse con [`crate::input::BlockingInput`].
+    pub fn input(mut self, input: impl InputReader + Send + 'static) -> Self {
+        self.input = Some(Box::new(input));
+        self
+    }
+
+    /// Define la salida donde escriben `.`, `EMIT`, `XEMIT`, `CR` y `."`. Por
+    /// defecto se usa la salida estándar con buffer.
+    ///
+    /// # Parámetros
+    /// - `output`: Destino de la salida, por ejemplo un archivo o un [`crate::output::OutputBuffer`].
+    pub fn output(mut self, output: impl Write + Send + 'static) -> Self {
+        self.output = Some(Box::new(output));
+        self
+    }
+
+    /// Define cómo se resuelven los resultados de `+ - * /` que no entran en una celda. Por
+    /// defecto se usa aritmética modular.
+    ///
+    /// # Parámetros
+    /// - `overflow`: Política de desborde.
+    pub fn overflow_policy(mut self, overflow: OverflowPolicy) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Define cómo se redondean los cocientes de `/`, `MOD`, `/MOD`, `*/` y `*/MOD`. Por defecto
+    /// la división es simétrica (redondea hacia cero).
+    ///
+    /// # Parámetros
+    /// - `division`: Modo de división.
+    pub fn division_mode(mut self, division: DivisionMode) -> Self {
+        self.division = division;
+        self
+    }
+
+    /// Define si `AND` y `OR` son operaciones lógicas (cualquier valor distinto de cero es
+    /// verdadero) o bit a bit, como indica el estándar. Por defecto son lógicas.
+    ///
+    /// # Parámetros
+    /// - `logic`: Comportamiento de `AND` y `OR`.
+    pub fn logic_mode(mut self, logic: LogicMode) -> Self {
+        self.logic = logic;
+        self
+    }
+
+    /// Define si `ROT` mueve la base de la pila al tope, como en versiones anteriores del
+    /// intérprete, en lugar de rotar los tres elementos superiores `( a b c -- b c a )`. Por
+    /// defecto se usa el `ROT` estándar.
+    ///
+    /// # Parámetros
+    /// - `legacy_rot`: Si es `true`, se usa el `ROT` anterior.
+    pub fn legacy_rot(mut self, legacy_rot: bool) -> Self {
+        self.legacy_rot = legacy_rot;
+        self
+    }
+
+    /// Define si `EMIT` escribe un espacio después de cada caracter, como en versiones anteriores
+    /// del intérprete. Por defecto `EMIT` escribe solo el caracter.
+    ///
+    /// # Parámetros
+    /// - `spaced_emit`: Si es `true`, se escribe el espacio.
+    pub fn spaced_emit(mut self, spaced_emit: bool) -> Self {
+        self.spaced_emit = spaced_emit;
+        self
+    }
+
+    /// Define el ancho de las celdas de la pila, la pila de retorno y el espacio de datos. Los
+    /// tamaños en bytes de las pilas se dividen por el tamaño de una celda. Por defecto las celdas
+    /// son de 16 bits.
+    ///
+    /// # Parámetros
+    /// - `width`: Ancho de las celdas.
+    pub fn cell_width(mut self, width: CellWidth) -> Self {
+        self.cell_width = width;
+        self
+    }
+
+    /// Crea el intérprete con la configuración indicada.
+    pub fn build(self) -> Interpreter {
+        let mut state = InterpreterState::with_cell_width(self.return_stack_size, self.cell_width);
+        if let Some(input) = self.input {
+            state.input = InputSource::new(input);
+        }
+        state.output = self.output.unwrap_or_else(stdout_output);
+        state.overflow = self.overflow;
+        state.division = self.division;
+        state.logic = self.logic;
+        state.legacy_rot = self.legacy_rot;
+        state.spaced_emit = self.spaced_emit;
+
+        Interpreter {
+            stack: Stack::with_cell_width(self.stack_size, self.cell_width),
+            state,
+            pending: String::new(),
+        }
+    }
+}
+
+impl Default for Interpreter {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl Interpreter {
+    /// Crea un intérprete con los tamaños por defecto.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Obtiene un constructor para configurar el intérprete.
+    pub fn builder() -> InterpreterBuilder {
+        InterpreterBuilder::default()
+    }
+
+    /// Compila y ejecuta código Forth.
+    ///
+    /// # Parámetros
+    /// - `source`: Código a ejecutar.
+    ///
+    /// # Retorna
+    /// - `Ok(())` si el código se ejecutó correctamente.
+    /// - `Err(ForthError)` con el error producido, asociado a la posición del token que lo causó.
+    ///   La pila conserva los valores que tenía al momento del error.
+    pub fn eval(&mut self, source: &str) -> Result<(), ForthError> {
+        let result = execute_operation(&mut self.stack, source.to_string(), &mut self.state);
+        self.flush()?;
+        result
+    }
+
+    /// Compila y ejecuta una línea de una sesión interactiva.
+    ///
+    /// A diferencia de [`Interpreter::eval`], si la línea termina dentro de una definición, una
+    /// estructura de control, una cadena o un comentario, no se produce un error: el texto desde
+    /// su comienzo queda pendiente y se completa con las líneas siguientes.
+    ///
+    /// # Parámetros
+    /// - `line`: Línea a ejecutar.
+    ///
+    /// # Retorna
+    /// - `Ok(())` si la línea se ejecutó correctamente o quedó pendiente
+    ///   (ver [`Interpreter::is_pending`]).
+    /// - `Err(ForthError)` con el error producido, asociado a la posición del token que lo causó
+    ///   dentro del texto pendiente. El texto pendiente se descarta.
+    pub fn eval_line(&mut self, line: &str) -> Result<(), ForthError> {
+        let result = execute_line(&mut self.stack, &mut self.pending, line, &mut self.state);
+        self.flush()?;
+        result
+    }
+
+    /// Indica si quedó texto pendiente de una línea anterior, a la espera de las líneas que
+    /// completen su definición, estructura de control, cadena o comentario.
+    pub fn is_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Lee y ejecuta un archivo con código Forth.
+    ///
+    /// # Parámetros
+    /// - `filename`: Nombre del archivo a ejecutar.
+    ///
+    /// # Retorna
+    /// - `Ok(())` si el código se ejecutó correctamente.
+    /// - `Err(ForthError)` si el archivo no pudo leerse o su ejecución produjo un error, asociado
+    ///   al archivo y a la posición del token que lo causó.
+    pub fn eval_file(&mut self, filename: &str) -> Result<(), ForthError> {
+        let content = read_file(filename.to_string())?;
+        let result = execute_operation(&mut self.stack, content, &mut self.state)
+            .map_err(|error| error.in_file(filename));
+        self.flush()?;
+        result
+    }
+
+    /// Agrega un valor a la pila de datos. Si el valor no entra en una celda, se conservan sus
+    /// bits menos significativos.
+    ///
+    /// # Parámetros
+    /// - `value`: Valor a apilar.
+    ///
+    /// # Retorna
+    /// - `Ok(())` si el valor se agregó.
+    /// - `Err(ForthError::StackOverflow)` si la pila está llena.
+    pub fn push(&mut self, value: Cell) -> Result<(), ForthError> {
+        self.stack.push(value)
+    }
+
+    /// Elimina y devuelve el valor del tope de la pila de datos.
+    ///
+    /// # Retorna
+    /// `Some(Cell)` con el valor del tope, o `None` si la pila está vacía.
+    pub fn pop(&mut self) -> Option<Cell> {
+        self.stack.pop()
+    }
+
+    /// Obtiene los valores de la pila de datos, desde el fondo hasta el tope.
+    pub fn stack(&self) -> &[Cell] {
+        self.stack.as_slice()
+    }
+
+    /// Vacía la pila de datos, conservando el diccionario y el resto del estado.
+    pub fn clear_stack(&mut self) {
+        self.stack.clear();
+    }
+
+    /// Obtiene la salida del intérprete, para escribir en ella junto con la salida de los programas.
+    pub fn output(&mut self) -> &mut dyn Write {
+        &mut self.state.output
+    }
+
+    /// Escribe en su destino la salida pendiente del intérprete.
+    pub fn flush(&mut self) -> Result<(), ForthError> {
+        self.state.output.flush().map_err(output_error)
+    }
+
+    /// Obtiene el diccionario de palabras definidas por el usuario.
+    pub fn word_map(&self) -> &WordMap {
+        &self.state.word_map
+    }
+}
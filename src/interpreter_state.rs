@@ -0,0 +1,78 @@
+use crate::arithmetic_operations::{DivisionMode, OverflowPolicy};
+use crate::boolean_operations::LogicMode;
+use crate::cell::CellWidth;
+use crate::data_space::DataSpace;
+use crate::input::InputSource;
+use crate::loop_operations::LoopStack;
+use crate::output::{Output, stdout_output};
+use crate::return_stack::ReturnStack;
+use crate::word_definitions::WordMap;
+
+/// Cantidad máxima de celdas del espacio de datos.
+const DATA_SPACE_SIZE: usize = 16 * 1024;
+
+/// Estado del intérprete compartido por las operaciones, además de la pila de datos.
+///
+/// Agrupa el diccionario de palabras definidas por el usuario, la pila de control de los
+/// ciclos en ejecución, la pila de retorno, el espacio de datos, la entrada de donde leen y la
+/// salida donde escriben las operaciones, la política de desborde y el modo de división de la aritmética, el
+/// comportamiento de `AND` y `OR`, y si `ROT` y `EMIT` conservan el comportamiento de versiones
+/// anteriores.
+pub struct InterpreterState {
+    pub word_map: WordMap,
+    pub loops: LoopStack,
+    pub return_stack: ReturnStack,
+    pub data_space: DataSpace,
+    pub input: InputSource,
+    pub output: Output,
+    pub overflow: OverflowPolicy,
+    pub division: DivisionMode,
+    pub logic: LogicMode,
+    pub legacy_rot: bool,
+    pub spaced_emit: bool,
+}
+
+impl InterpreterState {
+    /// Crea un estado vacío con una pila de retorno del tamaño indicado.
+    ///
+    /// # Argumentos
+    ///
+    /// * `return_stack_size` - Tamaño máximo de la pila de retorno en bytes.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve un `InterpreterState` sin palabras definidas, ciclos en ejecución ni celdas
+    /// reservadas, que lee de la entrada estándar y escribe en la salida estándar, usa aritmética
+    /// modular con división simétrica, trata `AND` y `OR` como operaciones lógicas y usa el `ROT`
+    /// y el `EMIT` estándar, con celdas de 16 bits.
+    pub fn new(return_stack_size: usize) -> Self {
+        Self::with_cell_width(return_stack_size, CellWidth::default())
+    }
+
+    /// Crea un estado vacío con una pila de retorno del tamaño y el ancho de celda indicados.
+    ///
+    /// # Argumentos
+    ///
+    /// * `return_stack_size` - Tamaño máximo de la pila de retorno en bytes.
+    /// * `width` - El ancho de las celdas de la pila de retorno.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve un `InterpreterState` igual al de [`InterpreterState::new`], cuya pila de retorno
+    /// usa celdas del ancho indicado.
+    pub fn with_cell_width(return_stack_size: usize, width: CellWidth) -> Self {
+        InterpreterState {
+            word_map: WordMap::new(),
+            loops: LoopStack::new(),
+            return_stack: ReturnStack::with_cell_width(return_stack_size, width),
+            data_space: DataSpace::new(DATA_SPACE_SIZE),
+            input: InputSource::default(),
+            output: stdout_output(),
+            overflow: OverflowPolicy::default(),
+            division: DivisionMode::default(),
+            logic: LogicMode::default(),
+            legacy_rot: false,
+            spaced_emit: false,
+        }
+    }
+}
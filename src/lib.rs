@@ -1,9 +1,28 @@
 pub mod arithmetic_operations;
 pub mod boolean_operations;
-pub mod conditional_operations;
+pub mod cell;
+pub mod compiler;
+pub mod data_space;
+pub mod double_operations;
+pub mod errors;
+pub mod executor;
 pub mod file_handling;
 pub mod forth_basic_operations;
+pub mod input;
+pub mod input_operations;
+pub mod interpreter;
+pub mod interpreter_state;
+pub mod loop_operations;
+pub mod memory_operations;
+pub mod output;
 pub mod output_operations;
+pub mod pictured_output_operations;
 pub mod program;
+pub mod repl;
+pub mod return_stack;
+pub mod return_stack_operations;
 pub mod stack;
+pub mod string_operations;
+pub mod suggestions;
+pub mod tokenizer;
 pub mod word_definitions;
@@ -0,0 +1,161 @@
+use crate::cell::{Cell, CellWidth, DoubleCell};
+use crate::errors::ForthError;
+use crate::stack::Stack;
+
+/// Parámetros de control de un ciclo `DO ... LOOP` en ejecución.
+struct LoopFrame {
+    index: Cell,
+    limit: Cell,
+    unlooped: bool,
+}
+
+/// Pila de control de los ciclos contados en ejecución.
+///
+/// Cada `DO` agrega un `LoopFrame` con el índice y el límite del ciclo, que se elimina al
+/// terminar el ciclo o al abandonarlo con `LEAVE`.
+#[derive(Default)]
+pub struct LoopStack {
+    frames: Vec<LoopFrame>,
+}
+
+impl LoopStack {
+    /// Crea una pila de control de ciclos vacía.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Obtiene la cantidad de ciclos en ejecución.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve el número de ciclos `DO` anidados que se están ejecutando.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Indica si no hay ciclos en ejecución.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve `true` si no se está ejecutando ningún ciclo `DO`.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Descarta los ciclos más internos, conservando los primeros `len`.
+    ///
+    /// # Argumentos
+    ///
+    /// * `len` - Cantidad de ciclos a conservar.
+    pub fn truncate(&mut self, len: usize) {
+        self.frames.truncate(len);
+    }
+
+    /// Inicia un ciclo contado tomando de la pila el índice inicial (tope) y el límite.
+    ///
+    /// # Argumentos
+    ///
+    /// * `stack` - La pila de datos.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve `Ok(())` si el ciclo se inició, o `Err(ForthError::StackUnderflow)` si faltan argumentos.
+    pub fn start(&mut self, stack: &mut Stack) -> Result<(), ForthError> {
+        match (stack.pop(), stack.pop()) {
+            (Some(index), Some(limit)) => {
+                self.frames.push(LoopFrame {
+                    index,
+                    limit,
+                    unlooped: false,
+                });
+                Ok(())
+            }
+            _ => Err(ForthError::StackUnderflow),
+        }
+    }
+
+    /// Incrementa el índice del ciclo más interno.
+    ///
+    /// El ciclo termina cuando el índice cruza el límite entre `limit - 1` y `limit`, o si sus
+    /// parámetros fueron descartados con `UNLOOP`. Al terminar, se elimina de la pila de control.
+    ///
+    /// # Argumentos
+    ///
+    /// * `increment` - Valor a sumar al índice (`1` para `LOOP`).
+    /// * `width` - Ancho de las celdas, que determina dónde da la vuelta el índice.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve `Ok(true)` si el ciclo terminó, `Ok(false)` si debe volver a iterar, o un
+    /// `Err` si no hay ciclos en ejecución.
+    pub fn step(&mut self, increment: Cell, width: CellWidth) -> Result<bool, ForthError> {
+        let frame = self
+            .frames
+            .last_mut()
+            .ok_or(ForthError::LoopIndexOutsideLoop)?;
+
+        let increment = DoubleCell::from(increment);
+        let before = width.wrap(DoubleCell::from(frame.index) - DoubleCell::from(frame.limit));
+        let after = width.wrap(DoubleCell::from(before) + increment);
+        frame.index = width.wrap(DoubleCell::from(frame.index) + increment);
+        let increment = width.wrap(increment);
+
+        let finished = frame.unlooped || (before ^ after) & (before ^ increment) < 0;
+        if finished {
+            self.frames.pop();
+        }
+
+        Ok(finished)
+    }
+
+    /// Abandona el ciclo más interno.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve `Ok(())` si hay un ciclo en ejecución, o un `Err` en caso contrario.
+    pub fn leave(&mut self) -> Result<(), ForthError> {
+        self.frames
+            .pop()
+            .map(|_| ())
+            .ok_or(ForthError::OutsideLoop("LEAVE"))
+    }
+
+    /// Descarta los parámetros del ciclo más interno.
+    ///
+    /// Luego de `UNLOOP` el índice del ciclo deja de estar disponible y el ciclo no vuelve a iterar
+    /// al llegar a su `LOOP` o `+LOOP`.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve `Ok(())` si hay un ciclo en ejecución, o un `Err` en caso contrario.
+    pub fn unloop(&mut self) -> Result<(), ForthError> {
+        let frame = self
+            .frames
+            .iter_mut()
+            .rev()
+            .find(|frame| !frame.unlooped)
+            .ok_or(ForthError::OutsideLoop("UNLOOP"))?;
+
+        frame.unlooped = true;
+        Ok(())
+    }
+
+    /// Obtiene el índice del ciclo ubicado `depth` niveles por debajo del más interno.
+    ///
+    /// # Argumentos
+    ///
+    /// * `depth` - `0` para el ciclo más interno (`I`), `1` para el exterior (`J`).
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve `Ok(Cell)` con el índice, o un `Err` si no hay suficientes ciclos en ejecución.
+    pub fn index(&self, depth: usize) -> Result<Cell, ForthError> {
+        self.frames
+            .iter()
+            .rev()
+            .filter(|frame| !frame.unlooped)
+            .nth(depth)
+            .map(|frame| frame.index)
+            .ok_or(ForthError::LoopIndexOutsideLoop)
+    }
+}
@@ -1,18 +1,15 @@
-mod arithmetic_operations;
-mod boolean_operations;
-mod conditional_operations;
-mod file_handling;
-mod forth_basic_operations;
-mod output_operations;
-mod program;
-mod stack;
-mod word_definitions;
+use forth_interpreter::{program, repl};
 
 fn main() {
     match program::parse_args() {
-        Ok((filename, stack_size)) => {
-            if let Err(error_msg) = program::execute_program(stack_size, filename) {
-                println!("{}", error_msg);
+        Ok(config) => {
+            let result = match &config.filename {
+                Some(filename) if !config.repl => program::execute_program(&config, filename),
+                _ => repl::run_repl(&config),
+            };
+
+            if let Err(error) = result {
+                println!("{}", program::error_message(&error, config.plain_errors));
             }
         }
         Err(error_msg) => println!("{}", error_msg),
@@ -0,0 +1,236 @@
+use crate::cell::{Cell, DECIMAL_BASE, DoubleCell, format_number, radix};
+use crate::compiler::{DefiningWord, Instruction, Primitive};
+use crate::errors::ForthError;
+use crate::interpreter_state::InterpreterState;
+use crate::output::output_error;
+use crate::stack::Stack;
+use crate::word_definitions::{Definition, WordKind, create};
+use std::io::Write;
+
+/// Máscara que conserva la celda completa.
+const CELL_MASK: Cell = -1;
+/// Máscara que conserva el byte menos significativo de una celda.
+const CHAR_MASK: Cell = 0xFF;
+
+/// Operación primitiva del espacio de datos.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoryOp {
+    /// `@`
+    Fetch,
+    /// `!`
+    Store,
+    /// `+!`
+    PlusStore,
+    /// `?`
+    PrintCell,
+    /// `C@`
+    CharFetch,
+    /// `C!`
+    CharStore,
+    /// `HERE`
+    Here,
+    /// `ALLOT`
+    Allot,
+    /// `,`
+    Comma,
+    /// `CELLS`
+    Cells,
+    /// `BASE`
+    Base,
+    /// `DECIMAL`
+    Decimal,
+    /// `HEX`
+    Hex,
+    /// `BINARY`
+    Binary,
+    /// `OCTAL`
+    Octal,
+    /// `SPAN`
+    Span,
+}
+
+/// Aplica una operación sobre el espacio de datos del intérprete.
+///
+/// # Operadores soportados
+/// - `@`, `!`, `+!`: Lee, escribe o incrementa la celda de la dirección indicada.
+/// - `?`: Imprime el contenido de la dirección indicada en la base actual, como `@ .`.
+/// - `C@`, `C!`: Lee o escribe el byte menos significativo de la celda de la dirección indicada.
+/// - `HERE`: Apila la dirección de la próxima celda libre.
+/// - `ALLOT`: Reserva la cantidad de celdas indicada.
+/// - `,`: Reserva una celda y almacena en ella el valor tomado de la pila.
+/// - `CELLS`: Convierte una cantidad de celdas en unidades de dirección.
+/// - `BASE`: Apila la dirección de la variable con la base numérica de la entrada y la salida.
+/// - `DECIMAL`, `HEX`, `BINARY`, `OCTAL`: Cambian la base numérica a 10, 16, 2 y 8.
+/// - `SPAN`: Apila la dirección de la variable con la cantidad de caracteres leídos por `EXPECT`.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `operator`: Operación a ejecutar.
+/// - `state`: Estado del intérprete con el espacio de datos.
+///
+/// # Retorna
+/// - `Ok(())` si la operación se ejecuta correctamente.
+/// - `Err(ForthError)` con:
+///   - `"stack-underflow"` si la pila no tiene suficientes elementos.
+///   - `"invalid-memory-address"` si se accede a una dirección no reservada.
+///   - `"data-space-overflow"` si se supera la capacidad del espacio de datos.
+///   - `ForthError::InvalidArgument` si `?` imprime con una base no válida.
+pub fn apply_memory_operation(
+    stack: &mut Stack,
+    operator: MemoryOp,
+    state: &mut InterpreterState,
+) -> Result<(), ForthError> {
+    match operator {
+        MemoryOp::Fetch => fetch(stack, state, CELL_MASK),
+        MemoryOp::Store => store(stack, state, CELL_MASK),
+        MemoryOp::PlusStore => plus_store(stack, state),
+        MemoryOp::PrintCell => print_cell(stack, state),
+        MemoryOp::CharFetch => fetch(stack, state, CHAR_MASK),
+        MemoryOp::CharStore => store(stack, state, CHAR_MASK),
+        MemoryOp::Here => stack.push(state.data_space.here() as Cell),
+        MemoryOp::Allot => {
+            let count = stack.pop().ok_or(ForthError::StackUnderflow)?;
+            state.data_space.allot(count)
+        }
+        MemoryOp::Comma => {
+            let value = stack.pop().ok_or(ForthError::StackUnderflow)?;
+            state.data_space.append(value).map(|_| ())
+        }
+        MemoryOp::Cells => {
+            let count = stack.pop().ok_or(ForthError::StackUnderflow)?;
+            stack.push(count)
+        }
+        MemoryOp::Base => stack.push(state.data_space.base_address()),
+        MemoryOp::Decimal => set_base(state, DECIMAL_BASE),
+        MemoryOp::Hex => set_base(state, 16),
+        MemoryOp::Binary => set_base(state, 2),
+        MemoryOp::Octal => set_base(state, 8),
+        MemoryOp::Span => stack.push(state.data_space.span_address()),
+    }
+}
+
+/// Define una palabra asociada a un dato.
+///
+/// - `VARIABLE`: Reserva una celda inicializada en cero y define el nombre para apilar su dirección.
+/// - `CONSTANT`: Define el nombre para apilar el valor tomado del tope de la pila.
+/// - `VALUE`: Reserva una celda con el tope de la pila y define el nombre para apilar su contenido.
+/// - `CREATE`: Define el nombre para apilar la dirección de la próxima celda libre (ver
+///   [`create`]).
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `defining_word`: Palabra definidora ejecutada.
+/// - `name`: Nombre de la palabra a definir.
+/// - `state`: Estado del intérprete con el diccionario y el espacio de datos.
+///
+/// # Retorna
+/// - `Ok(())` si la palabra se definió correctamente.
+/// - `Err(ForthError)` si la pila no tiene el valor requerido o no hay lugar en el espacio de datos.
+pub fn define_data_word(
+    stack: &mut Stack,
+    defining_word: DefiningWord,
+    name: String,
+    state: &mut InterpreterState,
+) -> Result<(), ForthError> {
+    let definition = match defining_word {
+        DefiningWord::Variable => {
+            let address = state.data_space.append(0)? as Cell;
+            Definition::new(vec![Instruction::Literal(address)], WordKind::Colon)
+        }
+        DefiningWord::Constant => {
+            let value = stack.pop().ok_or(ForthError::StackUnderflow)?;
+            Definition::new(vec![Instruction::Literal(value)], WordKind::Colon)
+        }
+        DefiningWord::Value => {
+            let value = stack.pop().ok_or(ForthError::StackUnderflow)?;
+            let address = state.data_space.append(value)? as Cell;
+            let code = vec![
+                Instruction::Literal(address),
+                Instruction::Primitive(Primitive::Memory(MemoryOp::Fetch)),
+            ];
+            Definition::new(code, WordKind::Value(address))
+        }
+        DefiningWord::Create => return create(name, state),
+    };
+
+    state.word_map.insert(name, definition);
+    Ok(())
+}
+
+/// Reemplaza el contenido de un `VALUE` con el valor tomado del tope de la pila.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `address`: Dirección de la celda del `VALUE`.
+/// - `state`: Estado del intérprete con el espacio de datos.
+pub fn store_value(
+    stack: &mut Stack,
+    address: Cell,
+    state: &mut InterpreterState,
+) -> Result<(), ForthError> {
+    let value = stack.pop().ok_or(ForthError::StackUnderflow)?;
+    state.data_space.store(address, value)
+}
+
+/// Reemplaza la dirección del tope de la pila por el contenido de esa celda.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `state`: Estado del intérprete con el espacio de datos.
+/// - `mask`: Máscara aplicada al valor leído (`C@` solo conserva el byte menos significativo).
+fn fetch(stack: &mut Stack, state: &InterpreterState, mask: Cell) -> Result<(), ForthError> {
+    let address = stack.pop().ok_or(ForthError::StackUnderflow)?;
+    let value = state.data_space.fetch(address)?;
+    stack.push(value & mask)
+}
+
+/// Almacena el segundo elemento de la pila en la dirección del tope.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `state`: Estado del intérprete con el espacio de datos.
+/// - `mask`: Máscara aplicada al valor almacenado (`C!` solo conserva el byte menos significativo).
+fn store(stack: &mut Stack, state: &mut InterpreterState, mask: Cell) -> Result<(), ForthError> {
+    match (stack.pop(), stack.pop()) {
+        (Some(address), Some(value)) => state.data_space.store(address, value & mask),
+        _ => Err(ForthError::StackUnderflow),
+    }
+}
+
+/// Suma el segundo elemento de la pila al contenido de la dirección del tope.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `state`: Estado del intérprete con el espacio de datos.
+fn plus_store(stack: &mut Stack, state: &mut InterpreterState) -> Result<(), ForthError> {
+    match (stack.pop(), stack.pop()) {
+        (Some(address), Some(increment)) => {
+            let current = state.data_space.fetch(address)?;
+            let sum = DoubleCell::from(current) + DoubleCell::from(increment);
+            state.data_space.store(address, stack.width().wrap(sum))
+        }
+        _ => Err(ForthError::StackUnderflow),
+    }
+}
+
+/// Imprime el contenido de la dirección del tope de la pila, en la base actual `( addr -- )`.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `state`: Estado del intérprete con el espacio de datos y la salida.
+fn print_cell(stack: &mut Stack, state: &mut InterpreterState) -> Result<(), ForthError> {
+    let address = stack.pop().ok_or(ForthError::StackUnderflow)?;
+    let value = state.data_space.fetch(address)?;
+    let radix = radix(state.data_space.base())?;
+    write!(state.output, "{}", format_number(value.into(), radix)).map_err(output_error)
+}
+
+/// Cambia la base numérica almacenada en `BASE`.
+///
+/// # Parámetros
+/// - `state`: Estado del intérprete con el espacio de datos.
+/// - `base`: Nueva base.
+fn set_base(state: &mut InterpreterState, base: Cell) -> Result<(), ForthError> {
+    state.data_space.set_base(base);
+    Ok(())
+}
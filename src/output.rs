@@ -0,0 +1,68 @@
+use crate::errors::{ForthError, IoError};
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// Salida donde escribe el intérprete. Debe poder enviarse a otro hilo junto con el intérprete.
+pub type Output = Box<dyn Write + Send>;
+
+/// Crea la salida por defecto del intérprete: la salida estándar con buffer.
+pub fn stdout_output() -> Output {
+    Box::new(io::BufWriter::new(io::stdout()))
+}
+
+/// Convierte una falla al escribir en la salida en un error del intérprete.
+///
+/// # Parámetros
+/// - `error`: Error de entrada/salida producido al escribir.
+pub fn output_error(error: io::Error) -> ForthError {
+    ForthError::Io(IoError::new(
+        "Error al escribir en la salida".to_string(),
+        error,
+    ))
+}
+
+/// Buffer en memoria que puede usarse como salida del intérprete y consultarse después.
+///
+/// Las copias del buffer comparten el mismo contenido, de modo que se puede entregar una copia al
+/// intérprete y conservar otra para leer lo que escribió, incluso desde otro hilo.
+#[derive(Debug, Clone, Default)]
+pub struct OutputBuffer {
+    bytes: Arc<Mutex<Vec<u8>>>,
+}
+
+impl OutputBuffer {
+    /// Crea un buffer vacío.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<u8>> {
+        self.bytes.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Obtiene el texto escrito en el buffer.
+    pub fn contents(&self) -> String {
+        String::from_utf8_lossy(&self.lock()).to_string()
+    }
+
+    /// Obtiene los bytes escritos en el buffer.
+    pub fn bytes(&self) -> Vec<u8> {
+        self.lock().clone()
+    }
+
+    /// Descarta el contenido del buffer.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+}
+
+impl Write for OutputBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.lock().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
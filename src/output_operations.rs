@@ -1,29 +1,98 @@
+use crate::cell::{Cell, DoubleCell, format_number, radix};
+use crate::errors::ForthError;
+use crate::output::output_error;
 use crate::stack::Stack;
+use crate::tokenizer::Tokenizer;
+use std::io::Write;
+
+/// Código del caracter espacio, que `BL` deja en la pila.
+const BLANK: Cell = 32;
+
+/// Cantidad máxima de espacios que se escriben de una vez.
+const SPACES_CHUNK: usize = 64;
+
+/// Forma en la que se interpreta el número a imprimir en un campo de ancho fijo.
+#[derive(Clone, Copy)]
+enum NumberKind {
+    /// Una celda con signo.
+    Signed,
+    /// Una celda sin signo.
+    Unsigned,
+    /// Un número doble con signo.
+    Double,
+}
+
+/// Operación primitiva de salida.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputOp {
+    /// `CR`
+    Cr,
+    /// `.`
+    Point,
+    /// `U.`
+    UnsignedPoint,
+    /// `D.`
+    DoublePoint,
+    /// `.R`
+    RightAligned,
+    /// `U.R`
+    UnsignedRightAligned,
+    /// `D.R`
+    DoubleRightAligned,
+    /// `.S`
+    PrintStack,
+    /// `EMIT`
+    Emit,
+    /// `XEMIT`
+    ExtendedEmit,
+    /// `SPACE`
+    Space,
+    /// `SPACES`
+    Spaces,
+    /// `BL`
+    Blank,
+}
 
 /// Aplica una operación de salida sobre la pila.
 ///
 /// # Argumentos
 ///
 /// * `stack` - La pila sobre la cual se realiza la operación.
-/// * `operator` - El operador de salida que se va a ejecutar (CR, .).
+/// * `operator` - La operación de salida que se va a ejecutar (CR, ., U., D., .R, U.R, D.R, .S,
+///   EMIT, XEMIT, SPACE, SPACES, BL).
+/// * `output` - La salida donde se escribe.
+/// * `base` - El contenido de `BASE`, la base en la que se imprimen los números.
+/// * `spaced_emit` - Si es `true`, `EMIT` escribe un espacio después del caracter.
 ///
 /// # Errores
 ///
-/// Retorna un `Err(String)` si el operador no es reconocido.
+/// Retorna un `Err(ForthError)` si falla la escritura o si se imprime un número y `base` no es
+/// una base válida.
 pub fn apply_output_operation(
     stack: &mut Stack,
-    operator: &str,
-    tokens: &mut std::str::SplitWhitespace,
-) -> Result<(), String> {
+    operator: OutputOp,
+    output: &mut dyn Write,
+    base: Cell,
+    spaced_emit: bool,
+) -> Result<(), ForthError> {
     match operator {
-        "CR" => {
-            println!();
-            Ok(())
+        OutputOp::Cr => writeln!(output).map_err(output_error),
+        OutputOp::Emit => emit(stack, output, spaced_emit),
+        OutputOp::ExtendedEmit => extended_emit(stack, output),
+        OutputOp::Point => point(stack, output, radix(base)?),
+        OutputOp::UnsignedPoint => unsigned_point(stack, output, radix(base)?),
+        OutputOp::DoublePoint => double_point(stack, output, radix(base)?),
+        OutputOp::RightAligned => right_aligned(stack, output, radix(base)?, NumberKind::Signed),
+        OutputOp::UnsignedRightAligned => {
+            right_aligned(stack, output, radix(base)?, NumberKind::Unsigned)
         }
-        "EMIT" => emit(stack),
-        "." => point(stack),
-        ".\"" => print_string(tokens),
-        _ => Err("?".to_string()),
+        OutputOp::DoubleRightAligned => {
+            right_aligned(stack, output, radix(base)?, NumberKind::Double)
+        }
+        OutputOp::PrintStack => print_stack(stack, output, radix(base)?),
+        OutputOp::Space => write!(output, " ").map_err(output_error),
+        OutputOp::Spaces => spaces(stack, output),
+        OutputOp::Blank => stack.push(BLANK),
     }
 }
 
@@ -32,68 +101,246 @@ pub fn apply_output_operation(
 /// # Argumentos
 ///
 /// * `stack` - La pila sobre la cual se realiza la operación.
+/// * `output` - La salida donde se escribe.
+/// * `radix` - La base en la que se imprime el número.
 ///
 /// # Errores
 ///
-/// Retorna un `Err(String)` si no hay elementos en la pila para imprimir.
-fn point(stack: &mut Stack) -> Result<(), String> {
+/// Retorna un `Err(ForthError)` si no hay elementos en la pila para imprimir.
+fn point(stack: &mut Stack, output: &mut dyn Write, radix: u32) -> Result<(), ForthError> {
     match stack.pop() {
         Some(value) => {
-            print!("{}", value);
-            Ok(())
+            write!(output, "{}", format_number(value.into(), radix)).map_err(output_error)
         }
-        _ => Err("stack-underflow".to_string()),
+        _ => Err(ForthError::StackUnderflow),
     }
 }
 
-/// Convierte a caracter ASCII e imprime el valor que se encuentra en la parte superior de la pila y lo elimina.
+/// Imprime como número sin signo el valor que se encuentra en la parte superior de la pila y lo
+/// elimina.
 ///
 /// # Argumentos
 ///
 /// * `stack` - La pila sobre la cual se realiza la operación.
+/// * `output` - La salida donde se escribe.
+/// * `radix` - La base en la que se imprime el número.
 ///
 /// # Errores
 ///
-/// Retorna un `Err(String)` si no hay elementos en la pila para imprimir.
-fn emit(stack: &mut Stack) -> Result<(), String> {
+/// Retorna un `Err(ForthError)` si no hay elementos en la pila para imprimir.
+fn unsigned_point(stack: &mut Stack, output: &mut dyn Write, radix: u32) -> Result<(), ForthError> {
     match stack.pop() {
         Some(value) => {
-            print!("{} ", value as u8 as char);
-            Ok(())
+            let value = stack.width().unsigned(value) as DoubleCell;
+            write!(output, "{}", format_number(value, radix)).map_err(output_error)
         }
-        _ => Err("stack-underflow".to_string()),
+        _ => Err(ForthError::StackUnderflow),
     }
 }
 
-/// Imprime por pantalla lo que recibe en el input que se encuentra entre ." (punto y comilla doble) y " (comilla doble)
+/// Imprime un número alineado a la derecha en un campo del ancho indicado por el tope de la pila
+/// (por ejemplo `( n ancho -- )` para `.R`). Si el número no entra en el campo, se imprime
+/// completo.
 ///
 /// # Argumentos
 ///
-/// * `tokens`: Iterador de los tokens restantes en la ejecución.
+/// * `stack` - La pila sobre la cual se realiza la operación.
+/// * `output` - La salida donde se escribe.
+/// * `radix` - La base en la que se imprime el número.
+/// * `kind` - Si el número es una celda con signo, sin signo o un número doble.
 ///
 /// # Errores
 ///
-/// Retorna un `Err(String)` si no hay elementos en la pila para imprimir.
-fn print_string(tokens: &mut std::str::SplitWhitespace) -> Result<(), String> {
-    let mut collected: Vec<&str> = Vec::new();
-    let mut found_closing_quote = false;
-
-    for token in tokens.by_ref() {
-        if token.ends_with('"') {
-            let trimmed = token.trim_end_matches('"');
-            collected.push(trimmed);
-            found_closing_quote = true;
-            break;
-        } else {
-            collected.push(token);
-        }
+/// Retorna un `Err(ForthError)` si la pila no tiene el ancho y el número a imprimir.
+fn right_aligned(
+    stack: &mut Stack,
+    output: &mut dyn Write,
+    radix: u32,
+    kind: NumberKind,
+) -> Result<(), ForthError> {
+    let required = match kind {
+        NumberKind::Double => 3,
+        NumberKind::Signed | NumberKind::Unsigned => 2,
+    };
+    if stack.len() < required {
+        return Err(ForthError::StackUnderflow);
     }
 
-    if !found_closing_quote {
-        return Err("Error: cadena de texto sin comilla final".to_string());
+    let field = stack.pop().ok_or(ForthError::StackUnderflow)?;
+    let value = match kind {
+        NumberKind::Signed => stack.pop().map(DoubleCell::from),
+        NumberKind::Unsigned => {
+            let width = stack.width();
+            stack.pop().map(|value| width.unsigned(value) as DoubleCell)
+        }
+        NumberKind::Double => stack.pop_double(),
     }
+    .ok_or(ForthError::StackUnderflow)?;
+
+    let text = format_number(value, radix);
+    let padding = usize::try_from(field)
+        .unwrap_or(0)
+        .saturating_sub(text.len());
+    write_spaces(output, padding)?;
+    write!(output, "{}", text).map_err(output_error)
+}
+
+/// Imprime la cantidad de espacios indicada por el tope de la pila y la elimina `( n -- )`.
+/// Una cantidad negativa no imprime nada.
+///
+/// # Argumentos
+///
+/// * `stack` - La pila sobre la cual se realiza la operación.
+/// * `output` - La salida donde se escribe.
+///
+/// # Errores
+///
+/// Retorna un `Err(ForthError)` si la pila está vacía.
+fn spaces(stack: &mut Stack, output: &mut dyn Write) -> Result<(), ForthError> {
+    let count = stack.pop().ok_or(ForthError::StackUnderflow)?;
+    write_spaces(output, usize::try_from(count).unwrap_or(0))
+}
 
-    let result = collected.join(" ");
-    print!("{} ", result);
+/// Escribe la cantidad de espacios indicada, en bloques de tamaño fijo.
+///
+/// # Argumentos
+///
+/// * `output` - La salida donde se escribe.
+/// * `count` - La cantidad de espacios.
+///
+/// # Errores
+///
+/// Retorna un `Err(ForthError)` si falla la escritura.
+fn write_spaces(output: &mut dyn Write, count: usize) -> Result<(), ForthError> {
+    let blanks = [b' '; SPACES_CHUNK];
+    let mut remaining = count;
+    while remaining > 0 {
+        let chunk = remaining.min(SPACES_CHUNK);
+        output.write_all(&blanks[..chunk]).map_err(output_error)?;
+        remaining -= chunk;
+    }
     Ok(())
 }
+
+/// Imprime el número doble que ocupa las dos celdas superiores de la pila y lo elimina.
+///
+/// # Argumentos
+///
+/// * `stack` - La pila sobre la cual se realiza la operación.
+/// * `output` - La salida donde se escribe.
+/// * `radix` - La base en la que se imprime el número.
+///
+/// # Errores
+///
+/// Retorna un `Err(ForthError)` si la pila no tiene dos elementos para imprimir.
+fn double_point(stack: &mut Stack, output: &mut dyn Write, radix: u32) -> Result<(), ForthError> {
+    match stack.pop_double() {
+        Some(value) => write!(output, "{}", format_number(value, radix)).map_err(output_error),
+        _ => Err(ForthError::StackUnderflow),
+    }
+}
+
+/// Imprime la cantidad de elementos de la pila y sus valores, desde el fondo hasta el tope, sin
+/// modificarla (por ejemplo `<3> 1 2 3 `).
+///
+/// # Argumentos
+///
+/// * `stack` - La pila a mostrar.
+/// * `output` - La salida donde se escribe.
+/// * `radix` - La base en la que se imprimen los valores.
+///
+/// # Errores
+///
+/// Retorna un `Err(ForthError)` si falla la escritura.
+fn print_stack(stack: &Stack, output: &mut dyn Write, radix: u32) -> Result<(), ForthError> {
+    write!(output, "<{}> ", stack.len()).map_err(output_error)?;
+    stack
+        .as_slice()
+        .iter()
+        .try_for_each(|value| {
+            write!(
+                output,
+                "{} ",
+                format_number(DoubleCell::from(*value), radix)
+            )
+        })
+        .map_err(output_error)
+}
+
+/// Escribe como un byte el valor que se encuentra en la parte superior de la pila y lo elimina.
+/// Solo se conserva el byte menos significativo del valor.
+///
+/// # Argumentos
+///
+/// * `stack` - La pila sobre la cual se realiza la operación.
+/// * `output` - La salida donde se escribe.
+/// * `spaced` - Si es `true`, se escribe un espacio después del byte.
+///
+/// # Errores
+///
+/// Retorna un `Err(ForthError)` si no hay elementos en la pila para imprimir.
+fn emit(stack: &mut Stack, output: &mut dyn Write, spaced: bool) -> Result<(), ForthError> {
+    let value = stack.pop().ok_or(ForthError::StackUnderflow)?;
+    let byte = value as u8;
+    if spaced {
+        output.write_all(&[byte, b' ']).map_err(output_error)
+    } else {
+        output.write_all(&[byte]).map_err(output_error)
+    }
+}
+
+/// Escribe codificado en UTF-8 el caracter Unicode cuyo código se encuentra en la parte superior
+/// de la pila y lo elimina. Un código que no corresponde a un caracter se escribe como `U+FFFD`.
+///
+/// # Argumentos
+///
+/// * `stack` - La pila sobre la cual se realiza la operación.
+/// * `output` - La salida donde se escribe.
+///
+/// # Errores
+///
+/// Retorna un `Err(ForthError)` si no hay elementos en la pila para imprimir.
+fn extended_emit(stack: &mut Stack, output: &mut dyn Write) -> Result<(), ForthError> {
+    let value = stack.pop().ok_or(ForthError::StackUnderflow)?;
+    write!(output, "{}", unicode_char(value)).map_err(output_error)
+}
+
+/// Obtiene el caracter Unicode de un código, o `U+FFFD` si el código no corresponde a un
+/// caracter.
+///
+/// # Argumentos
+///
+/// * `value` - El código del caracter.
+pub fn unicode_char(value: Cell) -> char {
+    u32::try_from(value)
+        .ok()
+        .and_then(char::from_u32)
+        .unwrap_or(char::REPLACEMENT_CHARACTER)
+}
+
+/// Obtiene el texto que se encuentra en el input entre ." (punto y comilla doble) o S" y la
+/// siguiente " (comilla doble), conservando sus espacios.
+///
+/// # Argumentos
+///
+/// * `tokens`: Iterador de los tokens restantes en la compilación.
+///
+/// # Errores
+///
+/// Retorna un `Err(ForthError)` si no se encuentra la comilla final.
+pub fn parse_string(tokens: &mut Tokenizer) -> Result<String, ForthError> {
+    tokens
+        .parse_until('"', false)
+        .map(str::to_string)
+        .ok_or(ForthError::UnterminatedString)
+}
+
+/// Imprime por pantalla el texto de un ." (punto y comilla doble), tal como fue escrito.
+///
+/// # Argumentos
+///
+/// * `text`: Texto a imprimir.
+/// * `output`: Salida donde se escribe.
+pub fn print_string(text: &str, output: &mut dyn Write) -> Result<(), ForthError> {
+    write!(output, "{}", text).map_err(output_error)
+}
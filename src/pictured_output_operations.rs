@@ -0,0 +1,110 @@
+use crate::cell::{Cell, DoubleCell, radix};
+use crate::errors::ForthError;
+use crate::interpreter_state::InterpreterState;
+use crate::stack::Stack;
+
+/// Operación primitiva de conversión numérica.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PicturedOutputOp {
+    /// `<#`
+    Begin,
+    /// `#`
+    Digit,
+    /// `#S`
+    Digits,
+    /// `HOLD`
+    Hold,
+    /// `SIGN`
+    Sign,
+    /// `#>`
+    End,
+}
+
+/// Aplica una operación de conversión numérica, que construye el texto de un número en el área
+/// de conversión del espacio de datos, desde el dígito menos significativo hacia el más
+/// significativo.
+///
+/// # Operadores soportados
+/// - `<#`: Comienza una conversión, vaciando el área de conversión `( -- )`.
+/// - `#`: Agrega el dígito menos significativo de un doble sin signo en la base actual y deja el
+///   resto del número `( ud -- ud' )`.
+/// - `#S`: Agrega todos los dígitos de un doble sin signo, al menos uno `( ud -- 0 0 )`.
+/// - `HOLD`: Agrega un caracter `( char -- )`.
+/// - `SIGN`: Agrega un `-` si el número es negativo `( n -- )`.
+/// - `#>`: Termina la conversión y deja la dirección y la longitud del texto `( xd -- addr u )`.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `operator`: Operación a ejecutar.
+/// - `state`: Estado del intérprete con el espacio de datos.
+///
+/// # Retorna
+/// - `Ok(())` si la operación se ejecuta correctamente.
+/// - `Err(ForthError)` con:
+///   - `"stack-underflow"` si la pila no tiene suficientes elementos.
+///   - `"pictured-output-overflow"` si el texto no entra en el área de conversión.
+pub fn apply_pictured_output_operation(
+    stack: &mut Stack,
+    operator: PicturedOutputOp,
+    state: &mut InterpreterState,
+) -> Result<(), ForthError> {
+    match operator {
+        PicturedOutputOp::Begin => {
+            state.data_space.begin_pictured();
+            Ok(())
+        }
+        PicturedOutputOp::Digit => digit(stack, state),
+        PicturedOutputOp::Digits => digits(stack, state),
+        PicturedOutputOp::Hold => {
+            let char = stack.pop().ok_or(ForthError::StackUnderflow)?;
+            state.data_space.hold(char)
+        }
+        PicturedOutputOp::Sign => {
+            let value = stack.pop().ok_or(ForthError::StackUnderflow)?;
+            if value < 0 {
+                state.data_space.hold(Cell::from(b'-'))?;
+            }
+            Ok(())
+        }
+        PicturedOutputOp::End => {
+            stack.pop_double().ok_or(ForthError::StackUnderflow)?;
+            let (address, length) = state.data_space.pictured();
+            stack.push(address)?;
+            stack.push(length)
+        }
+    }
+}
+
+/// Agrega el dígito menos significativo del doble sin signo del tope y lo reemplaza por el
+/// cociente de dividirlo por la base `( ud -- ud' )`.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `state`: Estado del intérprete con el espacio de datos.
+///
+/// # Retorna
+/// - `Ok(bool)` con `true` si el cociente es cero.
+/// - `Err(ForthError)` si la pila no tiene un doble, la base no es válida o el área está llena.
+fn convert_digit(stack: &mut Stack, state: &mut InterpreterState) -> Result<bool, ForthError> {
+    let value = stack.pop_double().ok_or(ForthError::StackUnderflow)?;
+    let radix = radix(state.data_space.base())?;
+    let value = stack.width().unsigned_double(value);
+
+    let digit = (value % u128::from(radix)) as u32;
+    let quotient = value / u128::from(radix);
+    let char = char::from_digit(digit, radix).map_or(b'0', |c| c.to_ascii_uppercase() as u8);
+    state.data_space.hold(Cell::from(char))?;
+    stack.push_double(quotient as DoubleCell)?;
+    Ok(quotient == 0)
+}
+
+/// Agrega el dígito menos significativo del doble sin signo del tope `( ud -- ud' )`.
+fn digit(stack: &mut Stack, state: &mut InterpreterState) -> Result<(), ForthError> {
+    convert_digit(stack, state).map(|_| ())
+}
+
+/// Agrega los dígitos del doble sin signo del tope hasta que el número sea cero `( ud -- 0 0 )`.
+fn digits(stack: &mut Stack, state: &mut InterpreterState) -> Result<(), ForthError> {
+    while !convert_digit(stack, state)? {}
+    Ok(())
+}
@@ -1,142 +1,346 @@
-use crate::arithmetic_operations::apply_arithmetic_operation;
-use crate::boolean_operations::apply_boolean_operation;
-use crate::conditional_operations::apply_conditional_operation;
-use crate::file_handling::{read_file, save_stack_to_file};
-use crate::forth_basic_operations::apply_forth_operation;
-use crate::output_operations::apply_output_operation;
+use crate::arithmetic_operations::{DivisionMode, OverflowPolicy};
+use crate::boolean_operations::LogicMode;
+use crate::cell::CellWidth;
+use crate::compiler::compile_item;
+use crate::errors::{ForthError, IoError};
+use crate::executor::execute_instructions;
+use crate::file_handling::save_stack_to_file;
+use crate::input::shared_stdin_input;
+use crate::interpreter::{Interpreter, InterpreterBuilder};
+use crate::interpreter_state::InterpreterState;
 use crate::stack::Stack;
+use crate::suggestions::suggest;
+use crate::tokenizer::Tokenizer;
 use crate::word_definitions::{WordMap, handle_word_definition};
 use std::env;
+use std::str::FromStr;
 
-const DEFAULT_STACK_SIZE: usize = 128 * 1024;
+pub(crate) const DEFAULT_STACK_SIZE: usize = 128 * 1024;
+pub(crate) const DEFAULT_RETURN_STACK_SIZE: usize = 16 * 1024;
+const PLAIN_ERRORS_FLAG: &str = "--plain-errors";
+const REPL_FLAG: &str = "--repl";
+const LEGACY_ROT_FLAG: &str = "--legacy-rot";
+const SPACED_EMIT_FLAG: &str = "--spaced-emit";
+const OVERFLOW_PREFIX: &str = "overflow=";
+const CELL_WIDTH_PREFIX: &str = "cell-width=";
+const DIVISION_PREFIX: &str = "division=";
+const LOGIC_PREFIX: &str = "logic=";
+
+/// Configuración de la ejecución indicada en la línea de comandos.
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    /// Nombre del archivo con el programa Forth a ejecutar, si se indicó.
+    pub filename: Option<String>,
+    /// Tamaño de la pila en bytes.
+    pub stack_size: usize,
+    /// Tamaño de la pila de retorno en bytes.
+    pub return_stack_size: usize,
+    /// Si es `true`, los errores se informan solo con su mensaje estándar (por ejemplo `?`), sin
+    /// posición ni sugerencias.
+    pub plain_errors: bool,
+    /// Si es `true`, se inicia el intérprete interactivo. Es el modo por defecto cuando no se
+    /// indica un archivo.
+    pub repl: bool,
+    /// Política de desborde de la aritmética.
+    pub overflow: OverflowPolicy,
+    /// Modo de división de la aritmética.
+    pub division: DivisionMode,
+    /// Comportamiento de `AND` y `OR`.
+    pub logic: LogicMode,
+    /// Si es `true`, `ROT` mueve la base de la pila al tope, como en versiones anteriores.
+    pub legacy_rot: bool,
+    /// Si es `true`, `EMIT` escribe un espacio después de cada caracter, como en versiones
+    /// anteriores.
+    pub spaced_emit: bool,
+    /// Ancho de las celdas.
+    pub cell_width: CellWidth,
+}
+
+impl Config {
+    /// Obtiene un constructor de intérprete con los tamaños y las opciones de la configuración.
+    pub fn interpreter_builder(&self) -> InterpreterBuilder {
+        Interpreter::builder()
+            .stack_size(self.stack_size)
+            .return_stack_size(self.return_stack_size)
+            .overflow_policy(self.overflow)
+            .division_mode(self.division)
+            .logic_mode(self.logic)
+            .legacy_rot(self.legacy_rot)
+            .spaced_emit(self.spaced_emit)
+            .cell_width(self.cell_width)
+    }
+}
 
 /// Ejecuta el programa especificado en el archivo.
 ///
 /// # Argumentos
 ///
-/// * `stack_size` - Tamaño de la pila.
+/// * `config` - La configuración de la ejecución, con los tamaños de las pilas y las opciones del intérprete.
 /// * `filename` - Nombre del archivo que contiene el programa Forth a ejecutar.
 ///
 /// # Retornos
 ///
 /// Devuelve `Ok(())` si el programa se ejecutó correctamente, o un `Err` con un mensaje de error en caso contrario.
-pub fn execute_program(stack_size: usize, filename: String) -> Result<(), String> {
-    let mut stack = Stack::new(stack_size);
-    let mut word_map = WordMap::new();
-    let result = match read_file(filename) {
-        Ok(content) => execute_operation(&mut stack, content, &mut word_map),
-        Err(error_msg) => Err(error_msg),
-    };
+pub fn execute_program(config: &Config, filename: &str) -> Result<(), ForthError> {
+    let mut interpreter = config
+        .interpreter_builder()
+        .input(shared_stdin_input())
+        .build();
+    let result = interpreter.eval_file(filename);
 
-    if let Err(e) = save_stack_to_file(&mut stack) {
-        return Err(format!("Error al guardar la pila en el archivo: {}", e));
+    if let Err(e) = save_stack_to_file(interpreter.stack()) {
+        return Err(ForthError::Io(IoError::new(
+            "Error al guardar la pila en el archivo".to_string(),
+            e,
+        )));
     }
 
     result
 }
 
-/// Analiza los argumentos de la línea de comandos.
+/// Construye el mensaje con el que se informa un error al usuario.
+///
+/// # Argumentos
+///
+/// * `error` - El error a informar.
+/// * `plain_errors` - Si es `true`, se usa solo el mensaje estándar del error, sin su posición ni sugerencias.
+pub fn error_message(error: &ForthError, plain_errors: bool) -> String {
+    if plain_errors {
+        error.root().to_string()
+    } else {
+        error.to_string()
+    }
+}
+
+/// Analiza los argumentos de la línea de comandos del proceso.
 ///
 /// # Retornos
 ///
-/// Devuelve un `Result` que contiene el nombre del archivo y el tamaño de la pila si la entrada es válida,
-/// o un mensaje de error si los argumentos no son adecuados.
-pub fn parse_args() -> Result<(String, usize), String> {
+/// Devuelve un `Result` que contiene la configuración de la ejecución si la entrada es válida,
+/// o un error si los argumentos no son adecuados.
+pub fn parse_args() -> Result<Config, ForthError> {
     let args: Vec<String> = env::args().collect();
-    if args.len() < 2 {
-        return Err("Error: Debes especificar un archivo .fth".to_string());
-    }
+    parse_config(&args)
+}
 
-    let filename = args[1].clone();
-    let stack_size = parse_stack_size(&args);
-    Ok((filename, stack_size))
+/// Analiza una lista de argumentos de la línea de comandos, cuyo primer elemento es el nombre
+/// del ejecutable.
+///
+/// # Argumentos
+///
+/// * `args` - Los argumentos de la línea de comandos.
+///
+/// # Retornos
+///
+/// Devuelve la configuración de la ejecución, o un error si alguna opción tiene un valor inválido.
+pub fn parse_config(args: &[String]) -> Result<Config, ForthError> {
+    let filename = args
+        .iter()
+        .skip(1)
+        .find(|arg| !arg.starts_with("--") && !arg.contains('='))
+        .cloned();
+
+    Ok(Config {
+        repl: filename.is_none() || args.iter().any(|arg| arg == REPL_FLAG),
+        filename,
+        stack_size: parse_size_arg(args, "stack-size=", DEFAULT_STACK_SIZE),
+        return_stack_size: parse_size_arg(args, "return-stack-size=", DEFAULT_RETURN_STACK_SIZE),
+        plain_errors: args.iter().any(|arg| arg == PLAIN_ERRORS_FLAG),
+        overflow: parse_option_arg(args, OVERFLOW_PREFIX)?,
+        division: parse_option_arg(args, DIVISION_PREFIX)?,
+        logic: parse_option_arg(args, LOGIC_PREFIX)?,
+        legacy_rot: args.iter().any(|arg| arg == LEGACY_ROT_FLAG),
+        spaced_emit: args.iter().any(|arg| arg == SPACED_EMIT_FLAG),
+        cell_width: parse_option_arg(args, CELL_WIDTH_PREFIX)?,
+    })
 }
 
 /// Ejecuta las operaciones definidas.
 ///
+/// Cada elemento de la entrada (una palabra, o una estructura de control completa) se compila
+/// una única vez a instrucciones que luego se ejecutan. Las definiciones con `:` se compilan y
+/// se agregan al diccionario.
+///
 /// # Argumentos
 ///
 /// * `stack` - La pila sobre la cual se ejecutan las operaciones.
 /// * `input` - El String con las operaciones a ejecutar.
+/// * `state` - Estado del intérprete con el diccionario, los ciclos y la pila de retorno.
 ///
 /// # Retornos
 ///
-/// Devuelve `Ok(())` si las operaciones se ejecutan correctamente, o un `Err` con el mensaje de error correspondiente.
+/// Devuelve `Ok(())` si las operaciones se ejecutan correctamente, o un `Err` con el error
+/// correspondiente, asociado a la línea y la columna del token que lo produjo.
 pub fn execute_operation(
     stack: &mut Stack,
     input: String,
-    word_map: &mut WordMap,
-) -> Result<(), String> {
-    let mut tokens = input.split_whitespace();
+    state: &mut InterpreterState,
+) -> Result<(), ForthError> {
+    execute_items(stack, &input, state).map_err(|(error, _)| error)
+}
 
-    while let Some(token) = tokens.next() {
-        let token_upc = token.to_uppercase();
-        // Si es una definición de palabra
-        if token_upc == ":" {
-            handle_word_definition(&mut tokens, word_map)?;
-            continue;
-        }
+/// Ejecuta una línea de una sesión interactiva, a continuación del texto que haya quedado
+/// pendiente de las líneas anteriores.
+///
+/// Si la entrada termina dentro de una definición, una estructura de control, una cadena o un
+/// comentario, los elementos anteriores se ejecutan y el texto del elemento incompleto queda en
+/// `pending`, para compilarlo completo cuando llegue la línea que lo termina.
+///
+/// # Argumentos
+///
+/// * `stack` - La pila sobre la cual se ejecutan las operaciones.
+/// * `pending` - El texto pendiente de las líneas anteriores; al terminar contiene el texto que
+///   quedó incompleto, o está vacío.
+/// * `line` - La línea a ejecutar.
+/// * `state` - Estado del intérprete con el diccionario, los ciclos y la pila de retorno.
+///
+/// # Retornos
+///
+/// Devuelve `Ok(())` si las operaciones se ejecutan correctamente o quedan pendientes, o un `Err`
+/// con el error correspondiente, en cuyo caso se descarta el texto pendiente.
+pub fn execute_line(
+    stack: &mut Stack,
+    pending: &mut String,
+    line: &str,
+    state: &mut InterpreterState,
+) -> Result<(), ForthError> {
+    let input = if pending.is_empty() {
+        line.to_string()
+    } else {
+        format!("{}\n{}", pending, line)
+    };
+    pending.clear();
 
-        // Si es una palabra definida por el usuario
-        if let Some(definition) = word_map.get(&token_upc) {
-            let definition_str = definition.join(" ");
-            execute_operation(stack, definition_str, word_map)?;
-            continue;
+    match execute_items(stack, &input, state) {
+        Err((error, Some(rest))) if error.is_incomplete() => {
+            *pending = rest.to_string();
+            Ok(())
         }
+        result => result.map_err(|(error, _)| error),
+    }
+}
+
+/// Compila y ejecuta uno a uno los elementos de la entrada.
+///
+/// Si la compilación de un elemento falla, se liberan las celdas que haya reservado en el espacio
+/// de datos (por ejemplo, para sus cadenas), de modo que volver a compilarlo no las duplique.
+///
+/// # Argumentos
+///
+/// * `stack` - La pila sobre la cual se ejecutan las operaciones.
+/// * `input` - El texto con las operaciones a ejecutar.
+/// * `state` - Estado del intérprete con el diccionario, los ciclos y la pila de retorno.
+///
+/// # Retornos
+///
+/// Devuelve `Ok(())` si las operaciones se ejecutan correctamente, o un `Err` con el error
+/// producido y, si se produjo al compilar, el texto desde el comienzo del elemento que lo causó.
+fn execute_items<'a>(
+    stack: &mut Stack,
+    input: &'a str,
+    state: &mut InterpreterState,
+) -> Result<(), (ForthError, Option<&'a str>)> {
+    let mut tokens = Tokenizer::new(input);
 
-        let result = match token_upc.as_str() {
-            "+" | "-" | "*" | "/" => apply_arithmetic_operation(stack, &token_upc),
-            "=" | "<" | ">" | "AND" | "OR" | "NOT" => apply_boolean_operation(stack, &token_upc),
-            "DUP" | "DROP" | "SWAP" | "OVER" | "ROT" => apply_forth_operation(stack, &token_upc),
-            "CR" | "." | "EMIT" | ".\"" => apply_output_operation(stack, &token_upc, &mut tokens),
-            "IF" => apply_conditional_operation(stack, &token_upc, &mut tokens, word_map),
-            _ => default_operation(stack, &token_upc),
+    loop {
+        let item = tokens.remaining();
+        let here = state.data_space.here();
+        let Some(token) = tokens.next() else {
+            return Ok(());
         };
 
-        result?
+        // Si es una definición de palabra
+        let compiled = if token == ":" {
+            handle_word_definition(
+                &mut tokens,
+                &mut state.word_map,
+                &mut state.data_space,
+                stack.width(),
+            )
+            .map(|()| None)
+        } else {
+            compile_item(
+                token,
+                &mut tokens,
+                &mut state.word_map,
+                &mut state.data_space,
+                stack.width(),
+            )
+            .map(Some)
+        };
+
+        match compiled {
+            Ok(Some(code)) => {
+                execute_instructions(stack, &code, state, &mut tokens).map_err(|e| (e, None))?
+            }
+            Ok(None) => {}
+            Err(error) => {
+                state.data_space.release_from(here);
+                let error = locate_compile_error(error, &tokens, &state.word_map);
+                return Err((error, Some(item)));
+            }
+        }
     }
+}
+
+/// Asocia un error de compilación al último token leído.
+///
+/// Si el error se debe a una palabra desconocida, se agregan las palabras conocidas más parecidas.
+///
+/// # Argumentos
+///
+/// * `error` - El error producido al compilar.
+/// * `tokens` - El tokenizador de la entrada, posicionado en el token que produjo el error.
+/// * `word_map` - El diccionario de palabras definidas por el usuario.
+fn locate_compile_error(error: ForthError, tokens: &Tokenizer, word_map: &WordMap) -> ForthError {
+    let suggestions = match &error {
+        ForthError::UnknownWord(word) => suggest(word, word_map),
+        _ => Vec::new(),
+    };
 
-    Ok(())
+    error.at(&tokens.source()).with_suggestions(suggestions)
 }
 
-/// Analiza el tamaño de la pila a partir de los argumentos de la línea de comandos.
+/// Analiza un tamaño en bytes a partir de los argumentos de la línea de comandos.
 ///
 /// # Argumentos
 ///
 /// * `args` - Los argumentos de la línea de comandos.
+/// * `prefix` - El prefijo del argumento que contiene el tamaño (por ejemplo `stack-size=`).
+/// * `default` - El valor a usar si el argumento no está presente o no es válido.
 ///
 /// # Retornos
 ///
-/// Devuelve el tamaño de la pila o un valor por defecto si no se encuentra en los argumentos.
-fn parse_stack_size(args: &[String]) -> usize {
+/// Devuelve el tamaño indicado o el valor por defecto si no se encuentra en los argumentos.
+fn parse_size_arg(args: &[String], prefix: &str, default: usize) -> usize {
     for arg in args {
-        if arg.starts_with("stack-size=") {
-            if let Some(size_str) = arg.split('=').nth(1) {
-                if let Ok(size) = size_str.parse::<usize>() {
-                    return size;
-                }
-            }
+        if let Some(size_str) = arg.strip_prefix(prefix)
+            && let Ok(size) = size_str.parse::<usize>()
+        {
+            return size;
         }
     }
 
-    DEFAULT_STACK_SIZE
+    default
 }
 
-/// Realiza la operación por defecto cuando el token no es reconocido como un operador.
+/// Analiza una opción con nombre a partir de los argumentos de la línea de comandos.
 ///
 /// # Argumentos
 ///
-/// * `stack` - La pila sobre la cual se ejecuta la operación.
-/// * `token` - El token que representa un número a agregar a la pila.
+/// * `args` - Los argumentos de la línea de comandos.
+/// * `prefix` - El prefijo del argumento que contiene el valor (por ejemplo `overflow=`).
 ///
 /// # Retornos
 ///
-/// Devuelve `Ok(())` si el número se agrega correctamente a la pila, o un `Err` si el token no es un número válido.
-fn default_operation(stack: &mut Stack, token: &str) -> Result<(), String> {
-    if let Ok(number) = token.parse::<i16>() {
-        stack.push(number);
-        Ok(())
-    } else {
-        Err("?".to_string())
+/// Devuelve el valor indicado, el valor por defecto si el argumento no está presente, o un error
+/// si el valor no es válido.
+fn parse_option_arg<T>(args: &[String], prefix: &str) -> Result<T, ForthError>
+where
+    T: FromStr<Err = ForthError> + Default,
+{
+    match args.iter().find_map(|arg| arg.strip_prefix(prefix)) {
+        Some(value) => value.parse(),
+        None => Ok(T::default()),
     }
 }
@@ -0,0 +1,93 @@
+use crate::errors::{ForthError, IoError};
+use crate::file_handling::save_stack_to_file;
+use crate::input::{StdinLines, shared_stdin_input};
+use crate::interpreter::Interpreter;
+use crate::output::output_error;
+use crate::program::{Config, error_message};
+use std::io::BufRead;
+
+/// Inicia el intérprete interactivo sobre la entrada y la salida estándar.
+///
+/// Si la configuración indica un archivo, primero se ejecuta su contenido, de modo que sus
+/// definiciones y su pila quedan disponibles en la sesión. Al terminar la entrada, la pila se
+/// guarda en el archivo de persistencia.
+///
+/// Las líneas se leen de a una, de modo que los programas pueden leer con `KEY` y `ACCEPT` el
+/// texto que sigue a la línea en ejecución, y `KEY?` no espera a que llegue un caracter.
+///
+/// # Argumentos
+///
+/// * `config` - La configuración de la ejecución.
+///
+/// # Retornos
+///
+/// Devuelve `Ok(())` al terminar la entrada, o un `Err` si falla la lectura o la escritura.
+pub fn run_repl(config: &Config) -> Result<(), ForthError> {
+    let mut interpreter = config
+        .interpreter_builder()
+        .input(shared_stdin_input())
+        .build();
+
+    if let Some(filename) = &config.filename
+        && let Err(error) = interpreter.eval_file(filename)
+    {
+        interpreter.clear_stack();
+        let message = error_message(&error, config.plain_errors);
+        writeln!(interpreter.output(), "{}", message).map_err(output_error)?;
+    }
+
+    let result = repl(StdinLines::new(), &mut interpreter, config.plain_errors);
+
+    if let Err(e) = save_stack_to_file(interpreter.stack()) {
+        return Err(ForthError::Io(IoError::new(
+            "Error al guardar la pila en el archivo".to_string(),
+            e,
+        )));
+    }
+
+    result
+}
+
+/// Ejecuta cada línea de la entrada con el mismo estado del intérprete, como un intérprete de
+/// texto Forth clásico.
+///
+/// Las definiciones y la pila se conservan entre líneas. Después de cada línea ejecutada
+/// correctamente se escribe ` ok`, a continuación de la salida que haya producido; si se produce
+/// un error, se informa y se vacía la pila de datos, conservando el diccionario y el resto del estado.
+///
+/// Una definición, estructura de control, cadena o comentario puede continuar en las líneas
+/// siguientes: mientras esté abierta, se escribe ` compiled` en lugar de ` ok`.
+///
+/// # Argumentos
+///
+/// * `input` - La entrada de donde se leen las líneas.
+/// * `interpreter` - El intérprete que ejecuta las líneas, en cuya salida se escriben `ok` y los errores.
+/// * `plain_errors` - Si es `true`, los errores se informan solo con su mensaje estándar.
+///
+/// # Retornos
+///
+/// Devuelve `Ok(())` al terminar la entrada, o un `Err` si falla la lectura o la escritura.
+pub fn repl<R: BufRead>(
+    input: R,
+    interpreter: &mut Interpreter,
+    plain_errors: bool,
+) -> Result<(), ForthError> {
+    for line in input.lines() {
+        let line = line
+            .map_err(|e| ForthError::Io(IoError::new("Error al leer la entrada".to_string(), e)))?;
+
+        let message = match interpreter.eval_line(&line) {
+            Ok(()) if interpreter.is_pending() => " compiled".to_string(),
+            Ok(()) => " ok".to_string(),
+            Err(error) => {
+                interpreter.clear_stack();
+                error_message(&error, plain_errors)
+            }
+        };
+
+        writeln!(interpreter.output(), "{}", message).map_err(output_error)?;
+        interpreter.flush()?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use crate::cell::{Cell, CellWidth};
+use crate::errors::ForthError;
+
+/// Estructura que representa la pila de retorno del intérprete.
+///
+/// Se dimensiona por separado de la pila de datos y, a diferencia de esta, informa el desborde
+/// y el vaciamiento con sus propios errores (`return-stack-overflow` y `return-stack-underflow`).
+pub struct ReturnStack {
+    elements: Vec<Cell>,
+    max_size: usize,
+}
+
+impl ReturnStack {
+    /// Crea una nueva pila de retorno con un tamaño máximo especificado en bytes.
+    ///
+    /// # Argumentos
+    ///
+    /// * `max_size_in_bytes` - El tamaño máximo de la pila de retorno en bytes.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve una instancia de `ReturnStack` de celdas de 16 bits, con capacidad para almacenar
+    /// `max_size_in_bytes / 2` elementos.
+    pub fn new(max_size_in_bytes: usize) -> Self {
+        Self::with_cell_width(max_size_in_bytes, CellWidth::default())
+    }
+
+    /// Crea una nueva pila de retorno con un tamaño máximo especificado en bytes y el ancho de
+    /// celda indicado.
+    ///
+    /// # Argumentos
+    ///
+    /// * `max_size_in_bytes` - El tamaño máximo de la pila de retorno en bytes.
+    /// * `width` - El ancho de las celdas.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve una instancia de `ReturnStack` con capacidad para almacenar `max_size_in_bytes`
+    /// dividido por la cantidad de bytes de una celda.
+    pub fn with_cell_width(max_size_in_bytes: usize, width: CellWidth) -> Self {
+        let max_size: usize = max_size_in_bytes / width.bytes();
+        ReturnStack {
+            elements: Vec::new(),
+            max_size,
+        }
+    }
+
+    /// Agrega un valor a la pila de retorno.
+    ///
+    /// # Argumentos
+    ///
+    /// * `value` - El valor que se quiere agregar a la pila de retorno.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve `Ok(())` si el valor se agregó, o `Err(ForthError::ReturnStackOverflow)` si la pila está llena.
+    pub fn push(&mut self, value: Cell) -> Result<(), ForthError> {
+        if self.elements.len() >= self.max_size {
+            return Err(ForthError::ReturnStackOverflow);
+        }
+
+        self.elements.push(value);
+        Ok(())
+    }
+
+    /// Elimina y devuelve el último valor de la pila de retorno.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve `Ok(Cell)` con el valor eliminado, o `Err(ForthError::ReturnStackUnderflow)` si la pila está vacía.
+    pub fn pop(&mut self) -> Result<Cell, ForthError> {
+        self.elements.pop().ok_or(ForthError::ReturnStackUnderflow)
+    }
+
+    /// Devuelve el último valor de la pila de retorno sin eliminarlo.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve `Ok(Cell)` con el valor del tope, o `Err(ForthError::ReturnStackUnderflow)` si la pila está vacía.
+    pub fn peek(&self) -> Result<Cell, ForthError> {
+        self.elements
+            .last()
+            .copied()
+            .ok_or(ForthError::ReturnStackUnderflow)
+    }
+
+    /// Obtiene la cantidad de elementos actuales en la pila de retorno.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve el número de elementos en la pila de retorno.
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Indica si la pila de retorno no contiene elementos.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve `true` si la pila de retorno está vacía.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+}
@@ -0,0 +1,117 @@
+use crate::errors::ForthError;
+use crate::return_stack::ReturnStack;
+use crate::stack::Stack;
+
+/// Operación primitiva de la pila de retorno.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReturnStackOp {
+    /// `>R`
+    ToR,
+    /// `R>`
+    RFrom,
+    /// `R@`
+    RFetch,
+    /// `2>R`
+    TwoToR,
+    /// `2R>`
+    TwoRFrom,
+}
+
+/// Aplica una operación que transfiere valores entre la pila de datos y la pila de retorno.
+///
+/// # Operadores soportados
+/// - `>R`: Mueve el tope de la pila de datos a la pila de retorno.
+/// - `R>`: Mueve el tope de la pila de retorno a la pila de datos.
+/// - `R@`: Copia el tope de la pila de retorno en la pila de datos.
+/// - `2>R`: Mueve los dos elementos superiores de la pila de datos a la pila de retorno, conservando su orden.
+/// - `2R>`: Mueve los dos elementos superiores de la pila de retorno a la pila de datos, conservando su orden.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `return_stack`: Referencia mutable a la pila de retorno.
+/// - `operator`: Operación a ejecutar.
+///
+/// # Retorna
+/// - `Ok(())` si la operación se ejecuta correctamente.
+/// - `Err(ForthError)` con:
+///   - `"stack-underflow"` si la pila de datos no tiene suficientes elementos.
+///   - `"return-stack-underflow"` si la pila de retorno no tiene suficientes elementos.
+///   - `"return-stack-overflow"` si la pila de retorno está llena.
+pub fn apply_return_stack_operation(
+    stack: &mut Stack,
+    return_stack: &mut ReturnStack,
+    operator: ReturnStackOp,
+) -> Result<(), ForthError> {
+    match operator {
+        ReturnStackOp::ToR => to_r(stack, return_stack),
+        ReturnStackOp::RFrom => r_from(stack, return_stack),
+        ReturnStackOp::RFetch => r_fetch(stack, return_stack),
+        ReturnStackOp::TwoToR => two_to_r(stack, return_stack),
+        ReturnStackOp::TwoRFrom => two_r_from(stack, return_stack),
+    }
+}
+
+/// Mueve el tope de la pila de datos a la pila de retorno.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `return_stack`: Referencia mutable a la pila de retorno.
+fn to_r(stack: &mut Stack, return_stack: &mut ReturnStack) -> Result<(), ForthError> {
+    let value = stack.pop().ok_or(ForthError::StackUnderflow)?;
+    return_stack.push(value)
+}
+
+/// Mueve el tope de la pila de retorno a la pila de datos.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `return_stack`: Referencia mutable a la pila de retorno.
+fn r_from(stack: &mut Stack, return_stack: &mut ReturnStack) -> Result<(), ForthError> {
+    let value = return_stack.pop()?;
+    stack.push(value)
+}
+
+/// Copia el tope de la pila de retorno en la pila de datos sin eliminarlo.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `return_stack`: Referencia a la pila de retorno.
+fn r_fetch(stack: &mut Stack, return_stack: &ReturnStack) -> Result<(), ForthError> {
+    let value = return_stack.peek()?;
+    stack.push(value)
+}
+
+/// Mueve los dos elementos superiores de la pila de datos a la pila de retorno.
+///
+/// `( x1 x2 -- ) ( R: -- x1 x2 )`
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `return_stack`: Referencia mutable a la pila de retorno.
+fn two_to_r(stack: &mut Stack, return_stack: &mut ReturnStack) -> Result<(), ForthError> {
+    match (stack.pop(), stack.pop()) {
+        (Some(x2), Some(x1)) => {
+            return_stack.push(x1)?;
+            return_stack.push(x2)
+        }
+        _ => Err(ForthError::StackUnderflow),
+    }
+}
+
+/// Mueve los dos elementos superiores de la pila de retorno a la pila de datos.
+///
+/// `( -- x1 x2 ) ( R: x1 x2 -- )`
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `return_stack`: Referencia mutable a la pila de retorno.
+fn two_r_from(stack: &mut Stack, return_stack: &mut ReturnStack) -> Result<(), ForthError> {
+    if return_stack.len() < 2 {
+        return Err(ForthError::ReturnStackUnderflow);
+    }
+
+    let x2 = return_stack.pop()?;
+    let x1 = return_stack.pop()?;
+    stack.push(x1)?;
+    stack.push(x2)
+}
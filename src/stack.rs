@@ -1,10 +1,14 @@
-/// Estructura que representa una pila de enteros de 16 bits (i16).
+use crate::cell::{Cell, CellWidth, DoubleCell};
+use crate::errors::ForthError;
+
+/// Estructura que representa una pila de celdas del ancho elegido (16 bits por defecto).
 ///
 /// La pila tiene un tamaño máximo en elementos determinado por el tamaño de memoria especificado
-/// al momento de la creación.
+/// al momento de la creación y el ancho de sus celdas.
 pub struct Stack {
-    elements: Vec<i16>,
+    elements: Vec<Cell>,
     max_size: usize,
+    width: CellWidth,
 }
 
 impl Stack {
@@ -16,28 +20,55 @@ impl Stack {
     ///
     /// # Retornos
     ///
-    /// Devuelve una instancia de `Stack` con capacidad para almacenar `max_size_in_bytes / 2` elementos.
+    /// Devuelve una instancia de `Stack` de celdas de 16 bits, con capacidad para almacenar
+    /// `max_size_in_bytes / 2` elementos.
     pub fn new(max_size_in_bytes: usize) -> Self {
-        let max_size: usize = max_size_in_bytes / 2;
+        Self::with_cell_width(max_size_in_bytes, CellWidth::default())
+    }
+
+    /// Crea una nueva pila con un tamaño máximo especificado en bytes y el ancho de celda indicado.
+    ///
+    /// # Argumentos
+    ///
+    /// * `max_size_in_bytes` - El tamaño máximo de la pila en bytes.
+    /// * `width` - El ancho de las celdas.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve una instancia de `Stack` con capacidad para almacenar `max_size_in_bytes` dividido
+    /// por la cantidad de bytes de una celda.
+    pub fn with_cell_width(max_size_in_bytes: usize, width: CellWidth) -> Self {
+        let max_size: usize = max_size_in_bytes / width.bytes();
         Stack {
             elements: Vec::with_capacity(max_size),
             max_size,
+            width,
         }
     }
 
+    /// Obtiene el ancho de las celdas de la pila.
+    pub fn width(&self) -> CellWidth {
+        self.width
+    }
+
     /// Agrega un valor a la pila si no se ha alcanzado el tamaño máximo.
     ///
-    /// Si la pila ya está llena, no se agrega el valor y se muestra un mensaje de error en la consola.
+    /// Si el valor no entra en una celda, se conservan sus bits menos significativos.
     ///
     /// # Argumentos
     ///
     /// * `value` - El valor que se quiere agregar a la pila.
-    pub fn push(&mut self, value: i16) {
-        if self.len() < self.max_size {
-            self.elements.push(value);
-        } else {
-            println!("stack-overflow");
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve `Ok(())` si el valor se agregó, o `Err(ForthError::StackOverflow)` si la pila está llena.
+    pub fn push(&mut self, value: Cell) -> Result<(), ForthError> {
+        if self.len() >= self.max_size {
+            return Err(ForthError::StackOverflow);
         }
+
+        self.elements.push(self.width.wrap(value.into()));
+        Ok(())
     }
 
     /// Elimina y devuelve el último valor de la pila.
@@ -46,11 +77,98 @@ impl Stack {
     ///
     /// # Retornos
     ///
-    /// Devuelve `Some(i16)` con el valor que fue eliminado de la pila, o `None` si la pila está vacía.
-    pub fn pop(&mut self) -> Option<i16> {
+    /// Devuelve `Some(Cell)` con el valor que fue eliminado de la pila, o `None` si la pila está vacía.
+    pub fn pop(&mut self) -> Option<Cell> {
         self.elements.pop()
     }
 
+    /// Agrega un número doble a la pila como dos celdas, con la más significativa en el tope.
+    ///
+    /// # Argumentos
+    ///
+    /// * `value` - El número doble que se quiere agregar a la pila.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve `Ok(())` si se agregaron ambas celdas, o `Err(ForthError::StackOverflow)` si la pila se llenó.
+    pub fn push_double(&mut self, value: DoubleCell) -> Result<(), ForthError> {
+        let (low, high) = self.width.split(value);
+        self.push(low)?;
+        self.push(high)
+    }
+
+    /// Elimina las dos celdas superiores de la pila y las devuelve como un número doble.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve `Some(DoubleCell)` con el número doble, o `None` (sin modificar la pila) si la
+    /// pila tiene menos de dos elementos.
+    pub fn pop_double(&mut self) -> Option<DoubleCell> {
+        if self.len() < 2 {
+            return None;
+        }
+
+        let high = self.pop()?;
+        let low = self.pop()?;
+        Some(self.width.join(low, high))
+    }
+
+    /// Obtiene, sin eliminarlo, el valor que está a la profundidad indicada.
+    ///
+    /// # Argumentos
+    ///
+    /// * `depth` - La profundidad del valor, donde `0` es el tope.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve `Some(Cell)` con el valor, o `None` si la pila no tiene tantos elementos.
+    pub fn pick(&self, depth: usize) -> Option<Cell> {
+        let index = self.len().checked_sub(depth.checked_add(1)?)?;
+        self.elements.get(index).copied()
+    }
+
+    /// Mueve al tope el valor que está a la profundidad indicada, desplazando hacia abajo los que
+    /// estaban encima (`2` rota los tres elementos superiores, como `ROT`).
+    ///
+    /// # Argumentos
+    ///
+    /// * `depth` - La profundidad del valor a mover, donde `0` es el tope.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve `Ok(())` si el valor se movió, o `Err(ForthError::StackUnderflow)` (sin modificar
+    /// la pila) si la pila no tiene tantos elementos.
+    pub fn roll(&mut self, depth: usize) -> Result<(), ForthError> {
+        let start = self.start_of(depth)?;
+        self.elements[start..].rotate_left(1);
+        Ok(())
+    }
+
+    /// Mueve el tope a la profundidad indicada, desplazando hacia arriba los que estaban encima
+    /// (`2` rota los tres elementos superiores en sentido inverso, como `-ROT`).
+    ///
+    /// # Argumentos
+    ///
+    /// * `depth` - La profundidad a la que se mueve el tope.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve `Ok(())` si el valor se movió, o `Err(ForthError::StackUnderflow)` (sin modificar
+    /// la pila) si la pila no tiene tantos elementos.
+    pub fn unroll(&mut self, depth: usize) -> Result<(), ForthError> {
+        let start = self.start_of(depth)?;
+        self.elements[start..].rotate_right(1);
+        Ok(())
+    }
+
+    /// Obtiene el índice del valor que está a la profundidad indicada.
+    fn start_of(&self, depth: usize) -> Result<usize, ForthError> {
+        depth
+            .checked_add(1)
+            .and_then(|count| self.len().checked_sub(count))
+            .ok_or(ForthError::StackUnderflow)
+    }
+
     /// Obtiene la cantidad de elementos actuales en la pila.
     ///
     /// # Retornos
@@ -59,4 +177,23 @@ impl Stack {
     pub fn len(&self) -> usize {
         self.elements.len()
     }
+
+    /// Obtiene los elementos de la pila, desde el fondo hasta el tope.
+    pub fn as_slice(&self) -> &[Cell] {
+        &self.elements
+    }
+
+    /// Elimina todos los elementos de la pila.
+    pub fn clear(&mut self) {
+        self.elements.clear();
+    }
+
+    /// Indica si la pila no contiene elementos.
+    ///
+    /// # Retornos
+    ///
+    /// Devuelve `true` si la pila está vacía.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
 }
@@ -0,0 +1,138 @@
+use crate::cell::Cell;
+use crate::errors::ForthError;
+use crate::interpreter_state::InterpreterState;
+use crate::output::output_error;
+use crate::output_operations::unicode_char;
+use crate::stack::Stack;
+use crate::tokenizer::Tokenizer;
+use std::io::Write;
+
+/// Máscara que conserva el byte menos significativo de una celda.
+const CHAR_MASK: Cell = 0xFF;
+
+/// Operación primitiva sobre cadenas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StringOp {
+    /// `TYPE`
+    Type,
+    /// `COUNT`
+    Count,
+    /// `XC-SIZE`
+    CharSize,
+}
+
+/// Aplica una operación sobre las cadenas almacenadas en el espacio de datos.
+///
+/// Cada caracter de una cadena ocupa una celda. Las cadenas de `S"` y `S\"` se representan en la
+/// pila con su dirección y su longitud; las de `C"` con la dirección de una celda que contiene la
+/// longitud, seguida de los caracteres.
+///
+/// # Operadores soportados
+/// - `TYPE`: Imprime la cadena indicada por la dirección y la longitud `( addr u -- )`.
+/// - `XC-SIZE`: Apila la cantidad de bytes que ocupa en UTF-8 un caracter Unicode
+///   `( xchar -- n )`.
+/// - `COUNT`: Convierte una cadena con contador en su dirección y longitud `( c-addr -- addr u )`.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `operator`: Operación a ejecutar.
+/// - `state`: Estado del intérprete con el espacio de datos y la salida.
+///
+/// # Retorna
+/// - `Ok(())` si la operación se ejecuta correctamente.
+/// - `Err(ForthError)` con:
+///   - `"stack-underflow"` si la pila no tiene suficientes elementos.
+///   - `"invalid-memory-address"` si la cadena no está en el espacio de datos reservado.
+pub fn apply_string_operation(
+    stack: &mut Stack,
+    operator: StringOp,
+    state: &mut InterpreterState,
+) -> Result<(), ForthError> {
+    match operator {
+        StringOp::Type => type_string(stack, state),
+        StringOp::Count => count(stack, state),
+        StringOp::CharSize => {
+            let value = stack.pop().ok_or(ForthError::StackUnderflow)?;
+            stack.push(unicode_char(value).len_utf8() as Cell)
+        }
+    }
+}
+
+/// Obtiene el texto que se encuentra en el input entre `S\"` y la siguiente `"` sin escapar,
+/// reemplazando las secuencias de escape.
+///
+/// Se reconocen `\a`, `\b`, `\e`, `\f`, `\l`, `\m` (retorno de carro y salto de línea), `\n`,
+/// `\q`, `\r`, `\t`, `\v`, `\z` (caracter nulo), `\"` y `\\`. Cualquier otro caracter precedido
+/// por `\` se conserva tal cual.
+///
+/// # Parámetros
+/// - `tokens`: Iterador de los tokens restantes en la compilación.
+///
+/// # Retorna
+/// - `Ok(String)` con el texto procesado.
+/// - `Err(ForthError::UnterminatedString)` si no se encuentra la comilla final.
+pub fn parse_escaped_string(tokens: &mut Tokenizer) -> Result<String, ForthError> {
+    let raw = tokens
+        .parse_until('"', true)
+        .ok_or(ForthError::UnterminatedString)?;
+
+    let mut text = String::with_capacity(raw.len());
+    let mut chars = raw.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('a') => text.push('\x07'),
+            Some('b') => text.push('\x08'),
+            Some('e') => text.push('\x1B'),
+            Some('f') => text.push('\x0C'),
+            Some('l' | 'n') => text.push('\n'),
+            Some('m') => text.push_str("\r\n"),
+            Some('q') => text.push('"'),
+            Some('r') => text.push('\r'),
+            Some('t') => text.push('\t'),
+            Some('v') => text.push('\x0B'),
+            Some('z') => text.push('\0'),
+            Some(other) => text.push(other),
+            None => text.push('\\'),
+        }
+    }
+
+    Ok(text)
+}
+
+/// Imprime los caracteres almacenados a partir de una dirección `( addr u -- )`.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `state`: Estado del intérprete con el espacio de datos y la salida.
+fn type_string(stack: &mut Stack, state: &mut InterpreterState) -> Result<(), ForthError> {
+    let (length, address) = match (stack.pop(), stack.pop()) {
+        (Some(length), Some(address)) => (length, address),
+        _ => return Err(ForthError::StackUnderflow),
+    };
+
+    let bytes = (0..length.max(0))
+        .map(|offset| {
+            let value = state.data_space.fetch(address.wrapping_add(offset))?;
+            Ok((value & CHAR_MASK) as u8)
+        })
+        .collect::<Result<Vec<u8>, ForthError>>()?;
+    state.output.write_all(&bytes).map_err(output_error)
+}
+
+/// Reemplaza la dirección de una cadena con contador por la dirección de su primer caracter y
+/// su longitud `( c-addr -- addr u )`.
+///
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `state`: Estado del intérprete con el espacio de datos.
+fn count(stack: &mut Stack, state: &InterpreterState) -> Result<(), ForthError> {
+    let address = stack.pop().ok_or(ForthError::StackUnderflow)?;
+    let length = state.data_space.fetch(address)?;
+    stack.push(address + 1)?;
+    stack.push(length)
+}
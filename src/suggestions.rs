@@ -0,0 +1,83 @@
+use crate::compiler::builtin_words;
+use crate::word_definitions::WordMap;
+
+/// Cantidad máxima de sugerencias para una palabra desconocida.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Busca las palabras conocidas más parecidas a una palabra desconocida.
+///
+/// Se consideran las palabras primitivas y las definidas por el usuario cuya distancia de edición
+/// a la palabra es la mínima encontrada y no supera un umbral que depende de su longitud, de modo
+/// que las palabras de un solo carácter no reciben sugerencias. La propia palabra nunca se sugiere.
+///
+/// # Parámetros
+/// - `word`: Palabra desconocida.
+/// - `word_map`: Diccionario con las palabras definidas por el usuario.
+///
+/// # Retorna
+/// Los nombres sugeridos en orden alfabético, o un vector vacío si ninguno es suficientemente parecido.
+pub fn suggest(word: &str, word_map: &WordMap) -> Vec<String> {
+    let word = word.to_uppercase();
+    let length = word.chars().count();
+    let max_distance = match length {
+        0 | 1 => 0,
+        2 | 3 => 1,
+        _ => 2,
+    };
+
+    let mut candidates: Vec<(usize, String)> = builtin_words()
+        .map(str::to_string)
+        .chain(word_map.names().cloned())
+        .filter(|candidate| *candidate != word)
+        .map(|candidate| (edit_distance(&word, &candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+
+    let Some(best) = candidates.iter().map(|(distance, _)| *distance).min() else {
+        return Vec::new();
+    };
+
+    candidates.retain(|(distance, _)| *distance == best);
+    let mut names: Vec<String> = candidates.into_iter().map(|(_, name)| name).collect();
+    names.sort();
+    names.dedup();
+    names.truncate(MAX_SUGGESTIONS);
+    names
+}
+
+/// Calcula la distancia de edición entre dos palabras.
+///
+/// Cuenta la mínima cantidad de inserciones, eliminaciones, sustituciones o transposiciones de
+/// caracteres adyacentes necesarias para transformar una palabra en la otra.
+///
+/// # Parámetros
+/// - `a`: Primera palabra.
+/// - `b`: Segunda palabra.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut previous: Vec<usize> = Vec::new();
+    let mut current: Vec<usize> = (0..=b.len()).collect();
+
+    for i in 1..=a.len() {
+        let before_previous = previous;
+        previous = current;
+        current = vec![i; b.len() + 1];
+
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (previous[j] + 1)
+                .min(current[j - 1] + 1)
+                .min(previous[j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(before_previous[j - 2] + 1);
+            }
+
+            current[j] = distance;
+        }
+    }
+
+    current[b.len()]
+}
@@ -0,0 +1,172 @@
+use std::fmt;
+
+/// Posición de un token en el código fuente, con línea y columna a partir de 1.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// Token del código fuente junto con su posición.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Source {
+    pub token: String,
+    pub location: Location,
+}
+
+/// Comienzo de un comentario que termina en el siguiente `)`.
+const PAREN_COMMENT: &str = "(";
+/// Comienzo de un comentario que termina al final de la línea.
+const LINE_COMMENT: &str = "\\";
+
+/// Iterador sobre los tokens separados por espacios de una entrada, que registra la línea y la
+/// columna de cada uno.
+///
+/// Los comentarios `( ... )` y `\ ...` (hasta el final de la línea) se descartan, por lo que
+/// pueden aparecer en cualquier lugar del programa, incluso dentro de definiciones. Si un `(` no
+/// tiene su `)`, se devuelve como token, para que el compilador informe el error en su posición.
+pub struct Tokenizer<'a> {
+    rest: &'a str,
+    line: usize,
+    column: usize,
+    last: Option<(&'a str, Location)>,
+}
+
+impl<'a> Tokenizer<'a> {
+    /// Crea un tokenizador posicionado al comienzo de la entrada.
+    ///
+    /// # Parámetros
+    ///
+    /// * `input` - Código fuente a recorrer.
+    pub fn new(input: &'a str) -> Self {
+        Tokenizer {
+            rest: input,
+            line: 1,
+            column: 1,
+            last: None,
+        }
+    }
+
+    /// Obtiene el último token leído y su posición.
+    ///
+    /// # Retorna
+    ///
+    /// El `Source` del último token devuelto por el iterador, o uno vacío si todavía no se leyó ninguno.
+    pub fn source(&self) -> Source {
+        self.last
+            .map(|(token, location)| Source {
+                token: token.to_string(),
+                location,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Obtiene la entrada que todavía no fue recorrida.
+    pub fn remaining(&self) -> &'a str {
+        self.rest
+    }
+
+    /// Obtiene el siguiente token sin descartar los comentarios.
+    ///
+    /// # Retorna
+    ///
+    /// `Some(&str)` con el token, o `None` si no quedan tokens en la entrada.
+    fn next_token(&mut self) -> Option<&'a str> {
+        let rest = self.rest;
+        let start = rest.find(|c: char| !c.is_whitespace())?;
+        self.advance(&rest[..start]);
+
+        let rest = self.rest;
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        let token = &rest[..end];
+        let location = Location {
+            line: self.line,
+            column: self.column,
+        };
+
+        self.advance(token);
+        self.last = Some((token, location));
+        Some(token)
+    }
+
+    /// Lee el texto literal que sigue al último token hasta el delimitador indicado, para las
+    /// palabras que leen cadenas, como `."` y `S"`.
+    ///
+    /// Se omite el espacio que separa el texto del token anterior; el resto de los espacios se
+    /// conserva. El delimitador se descarta.
+    ///
+    /// # Parámetros
+    ///
+    /// * `delimiter` - Caracter que termina el texto.
+    /// * `escapes` - Si es `true`, un caracter precedido por `\` no termina el texto.
+    ///
+    /// # Retorna
+    ///
+    /// `Some(&str)` con el texto, sin procesar las secuencias de escape, o `None` (sin avanzar)
+    /// si el delimitador no aparece.
+    pub fn parse_until(&mut self, delimiter: char, escapes: bool) -> Option<&'a str> {
+        let rest = self.rest;
+        let start = rest
+            .chars()
+            .next()
+            .filter(|c| c.is_whitespace())
+            .map_or(0, char::len_utf8);
+
+        let mut escaped = false;
+        let (length, _) = rest[start..].char_indices().find(|(_, c)| {
+            let found = !escaped && *c == delimiter;
+            escaped = escapes && !escaped && *c == '\\';
+            found
+        })?;
+
+        let end = start + length;
+        self.advance(&rest[..end + delimiter.len_utf8()]);
+        Some(&rest[start..end])
+    }
+
+    /// Descarta la entrada hasta el delimitador indicado inclusive, o hasta el final si no aparece.
+    ///
+    /// # Parámetros
+    ///
+    /// * `delimiter` - Caracter que termina el texto descartado.
+    fn skip_past(&mut self, delimiter: char) {
+        let rest = self.rest;
+        let end = rest
+            .find(delimiter)
+            .map_or(rest.len(), |index| index + delimiter.len_utf8());
+        self.advance(&rest[..end]);
+    }
+
+    /// Avanza sobre el texto indicado, actualizando la línea y la columna.
+    fn advance(&mut self, text: &'a str) {
+        for c in text.chars() {
+            if c == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+        }
+        self.rest = &self.rest[text.len()..];
+    }
+}
+
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        loop {
+            match self.next_token()? {
+                PAREN_COMMENT if self.rest.contains(')') => self.skip_past(')'),
+                LINE_COMMENT => self.skip_past('\n'),
+                token => return Some(token),
+            }
+        }
+    }
+}
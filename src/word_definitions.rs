@@ -1,29 +1,158 @@
+use crate::cell::{Cell, CellWidth};
+use crate::compiler::{Code, Instruction, compile_definition};
+use crate::data_space::DataSpace;
+use crate::errors::ForthError;
+use crate::interpreter_state::InterpreterState;
+use crate::stack::Stack;
+use crate::tokenizer::Tokenizer;
 use std::collections::HashMap;
+use std::sync::Arc;
 
-pub type WordMap = HashMap<String, Vec<String>>;
+/// Tipo de palabra definida, que determina qué operaciones admite además de su ejecución.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WordKind {
+    /// Palabra definida con `:`, `VARIABLE` o `CONSTANT`.
+    Colon,
+    /// Palabra definida con `VALUE`, con la dirección de la celda que modifica `TO`.
+    Value(Cell),
+    /// Palabra definida con `CREATE`, con la dirección de su cuerpo.
+    Created(Cell),
+}
+
+/// Definición compilada de una palabra.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Definition {
+    pub code: Arc<Code>,
+    pub kind: WordKind,
+    /// Nombre con el que se definió la palabra, o `None` si es anónima.
+    pub name: Option<String>,
+}
+
+impl Definition {
+    /// Crea una definición anónima a partir de sus instrucciones compiladas.
+    ///
+    /// # Parámetros
+    ///
+    /// * `code` - Instrucciones que se ejecutan al invocar la palabra.
+    /// * `kind` - Tipo de palabra definida.
+    pub fn new(code: impl Into<Code>, kind: WordKind) -> Self {
+        Definition {
+            code: Arc::new(code.into()),
+            kind,
+            name: None,
+        }
+    }
+}
+
+/// Diccionario de palabras definidas por el usuario.
+///
+/// Cada definición se identifica por su token de ejecución (su posición en el diccionario).
+/// Redefinir una palabra agrega una nueva definición y asocia el nombre a ella, por lo que las
+/// palabras compiladas con la definición anterior la siguen utilizando.
+#[derive(Default)]
+pub struct WordMap {
+    names: HashMap<String, usize>,
+    definitions: Vec<Definition>,
+    /// Token de ejecución y dirección del cuerpo de la última palabra creada con `CREATE`.
+    latest_created: Option<(usize, Cell)>,
+}
+
+impl WordMap {
+    /// Crea un diccionario vacío.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Obtiene la definición vigente de una palabra.
+    ///
+    /// # Parámetros
+    ///
+    /// * `name` - Nombre de la palabra en mayúsculas.
+    pub fn get(&self, name: &str) -> Option<&Definition> {
+        self.find(name).and_then(|xt| self.definition(xt))
+    }
+
+    /// Obtiene el token de ejecución de la definición vigente de una palabra.
+    ///
+    /// # Parámetros
+    ///
+    /// * `name` - Nombre de la palabra en mayúsculas.
+    pub fn find(&self, name: &str) -> Option<usize> {
+        self.names.get(name).copied()
+    }
+
+    /// Obtiene la definición asociada a un token de ejecución.
+    ///
+    /// # Parámetros
+    ///
+    /// * `xt` - Token de ejecución de la definición.
+    pub fn definition(&self, xt: usize) -> Option<&Definition> {
+        self.definitions.get(xt)
+    }
+
+    /// Agrega una definición con nombre, reemplazando la definición vigente de ese nombre.
+    ///
+    /// # Parámetros
+    ///
+    /// * `name` - Nombre de la palabra en mayúsculas.
+    /// * `definition` - Definición compilada.
+    ///
+    /// # Retorna
+    ///
+    /// El token de ejecución de la nueva definición.
+    pub fn insert(&mut self, name: String, mut definition: Definition) -> usize {
+        definition.name = Some(name.clone());
+        let xt = self.add_anonymous(definition);
+        self.names.insert(name, xt);
+        xt
+    }
+
+    /// Agrega una definición sin nombre, como el código que sigue a `DOES>`.
+    ///
+    /// # Parámetros
+    ///
+    /// * `definition` - Definición compilada.
+    ///
+    /// # Retorna
+    ///
+    /// El token de ejecución de la nueva definición.
+    pub fn add_anonymous(&mut self, definition: Definition) -> usize {
+        self.definitions.push(definition);
+        self.definitions.len() - 1
+    }
+
+    /// Obtiene los nombres de todas las palabras definidas.
+    pub fn names(&self) -> impl Iterator<Item = &String> {
+        self.names.keys()
+    }
+}
 
 /// Define una nueva palabra en el mapa de palabras del usuario.
 ///
 /// Esta función toma los tokens que siguen al símbolo `:` e interpreta el nombre
-/// y el cuerpo de la nueva palabra. Si el nombre es un número o si no se encuentra
-/// el símbolo `;` al final de la definición, se devuelve un error.
+/// y el cuerpo de la nueva palabra, que se compila a instrucciones. Si el nombre es un número
+/// o si no se encuentra el símbolo `;` al final de la definición, se devuelve un error.
 ///
 /// # Parámetros
 ///
 /// * `tokens` - Iterador sobre los tokens restantes de la entrada.
 /// * `word_map` - Mapa que almacena las palabras definidas por el usuario.
+/// * `data_space` - Espacio de datos donde se almacenan las cadenas literales de la definición.
+/// * `width` - Ancho de las celdas, que determina el rango de los literales numéricos.
 ///
 /// # Retorna
 ///
 /// * `Ok(())` si la definición fue exitosa.
-/// * `Err(String)` si ocurre un error en la definición.
+/// * `Err(ForthError)` si ocurre un error en la definición.
 pub fn handle_word_definition(
-    tokens: &mut std::str::SplitWhitespace,
+    tokens: &mut Tokenizer,
     word_map: &mut WordMap,
-) -> Result<(), String> {
+    data_space: &mut DataSpace,
+    width: CellWidth,
+) -> Result<(), ForthError> {
     let name = parse_word_name(tokens)?;
-    let definition = parse_word_body(tokens, word_map)?;
-    word_map.insert(name, definition);
+    let code = compile_definition(tokens, word_map, data_space, width)?;
+    word_map.insert(name, Definition::new(code, WordKind::Colon));
     Ok(())
 }
 
@@ -36,49 +165,108 @@ pub fn handle_word_definition(
 /// # Retornos
 ///
 /// * `Ok(String)` con el nombre en mayúsculas si es válido.
-/// * `Err(String)` si no se proporciona un nombre o si el nombre es un número.
-fn parse_word_name(tokens: &mut std::str::SplitWhitespace) -> Result<String, String> {
+/// * `Err(ForthError)` si no se proporciona un nombre o si el nombre es un número.
+pub fn parse_word_name(tokens: &mut Tokenizer) -> Result<String, ForthError> {
     let name = tokens
         .next()
-        .ok_or("Error: Se esperaba un nombre para la palabra")?
+        .ok_or(ForthError::MissingWordName)?
         .to_uppercase();
 
-    if name.parse::<i16>().is_ok() {
-        return Err("invalid-word".to_string());
+    if name.parse::<Cell>().is_ok() {
+        return Err(ForthError::InvalidWord);
     }
 
     Ok(name)
 }
 
-/// Parsea el cuerpo de una nueva palabra definida por el usuario.
+/// Operación primitiva sobre palabras definidas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefiningOp {
+    /// `>BODY`
+    ToBody,
+}
+
+/// Aplica una operación primitiva sobre las palabras definidas.
+///
+/// # Operadores soportados
+/// - `>BODY`: Convierte el token de ejecución de una palabra creada con `CREATE` en la dirección de su cuerpo.
 ///
 /// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `operator`: Operación a ejecutar.
+/// - `state`: Estado del intérprete con el diccionario.
 ///
-/// * `tokens` - Iterador sobre los tokens restantes de la entrada.
-/// * `word_map` - Mapa que contiene las palabras ya definidas.
+/// # Retorna
+/// - `Ok(())` si la operación se ejecuta correctamente.
+/// - `Err(ForthError)` con:
+///   - `"stack-underflow"` si la pila no tiene suficientes elementos.
+///   - `"invalid-memory-address"` si `>BODY` recibe una palabra que no fue creada con `CREATE`.
+pub fn apply_defining_operation(
+    stack: &mut Stack,
+    operator: DefiningOp,
+    state: &mut InterpreterState,
+) -> Result<(), ForthError> {
+    match operator {
+        DefiningOp::ToBody => to_body(stack, state),
+    }
+}
+
+/// Crea una palabra que apila la dirección de la próxima celda libre del espacio de datos.
+///
+/// # Parámetros
+/// - `name`: Nombre de la palabra a crear.
+/// - `state`: Estado del intérprete con el diccionario y el espacio de datos.
+pub fn create(name: String, state: &mut InterpreterState) -> Result<(), ForthError> {
+    let address = state.data_space.here() as Cell;
+    let definition = Definition::new(
+        vec![Instruction::Literal(address)],
+        WordKind::Created(address),
+    );
+
+    let xt = state.word_map.insert(name, definition);
+    state.word_map.latest_created = Some((xt, address));
+    Ok(())
+}
+
+/// Asigna a la última palabra creada con `CREATE` el comportamiento definido después de `DOES>`.
+///
+/// La palabra creada apila la dirección de su cuerpo y luego ejecuta el código indicado.
+///
+/// # Parámetros
+/// - `does_xt`: Token de ejecución del código que sigue a `DOES>`.
+/// - `state`: Estado del intérprete con el diccionario.
 ///
 /// # Retorna
+/// - `Ok(())` si se asignó el comportamiento.
+/// - `Err(ForthError::UnknownWord)` si no hay una palabra creada con `CREATE`.
+pub fn does(does_xt: usize, state: &mut InterpreterState) -> Result<(), ForthError> {
+    let word_map = &mut state.word_map;
+    let (definition, address) = word_map
+        .latest_created
+        .and_then(|(xt, address)| Some((word_map.definitions.get_mut(xt)?, address)))
+        .ok_or_else(|| ForthError::UnknownWord("DOES>".to_string()))?;
+
+    definition.code = Arc::new(Code::from(vec![
+        Instruction::Literal(address),
+        Instruction::Call(does_xt),
+    ]));
+    Ok(())
+}
+
+/// Reemplaza el token de ejecución del tope de la pila por la dirección del cuerpo de la palabra.
 ///
-/// * `Ok(Vec<String>)` con los tokens expandidos del cuerpo.
-/// * `Err(String)` si no se encuentra el símbolo `;` al final.
-fn parse_word_body(
-    tokens: &mut std::str::SplitWhitespace,
-    word_map: &WordMap,
-) -> Result<Vec<String>, String> {
-    let mut definition = Vec::new();
-
-    for token in tokens {
-        if token == ";" {
-            return Ok(definition);
-        }
+/// # Parámetros
+/// - `stack`: Referencia mutable a la pila de datos.
+/// - `state`: Estado del intérprete con el diccionario.
+fn to_body(stack: &mut Stack, state: &InterpreterState) -> Result<(), ForthError> {
+    let xt = stack.pop().ok_or(ForthError::StackUnderflow)?;
+    let kind = usize::try_from(xt)
+        .ok()
+        .and_then(|xt| state.word_map.definition(xt))
+        .map(|definition| definition.kind);
 
-        let token_up = token.to_uppercase();
-        if let Some(existing_def) = word_map.get(&token_up) {
-            definition.extend(existing_def.iter().cloned());
-        } else {
-            definition.push(token.to_string());
-        }
+    match kind {
+        Some(WordKind::Created(address)) => stack.push(address),
+        _ => Err(ForthError::InvalidMemoryAddress),
     }
-
-    Err("Error: Se esperaba ';' al final de la definición".to_string())
 }
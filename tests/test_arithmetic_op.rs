@@ -1,4 +1,8 @@
-use forth_interpreter::arithmetic_operations::apply_arithmetic_operation;
+use forth_interpreter::arithmetic_operations::{
+    ArithmeticOp, DivisionMode, OverflowPolicy, apply_arithmetic_operation,
+};
+use forth_interpreter::cell::Cell;
+use forth_interpreter::errors::ForthError;
 use forth_interpreter::stack::Stack;
 
 #[cfg(test)]
@@ -13,10 +17,15 @@ mod arithmetic_operations_test {
     fn test_addition() {
         let mut stack = setup_stack();
 
-        stack.push(2);
-        stack.push(3);
+        assert!(stack.push(2).is_ok());
+        assert!(stack.push(3).is_ok());
 
-        let result = apply_arithmetic_operation(&mut stack, "+");
+        let result = apply_arithmetic_operation(
+            &mut stack,
+            ArithmeticOp::Add,
+            OverflowPolicy::Wrap,
+            DivisionMode::Symmetric,
+        );
         assert_eq!(result, Ok(()));
         assert_eq!(stack.pop(), Some(5));
     }
@@ -25,10 +34,15 @@ mod arithmetic_operations_test {
     fn test_subtraction() {
         let mut stack = setup_stack();
 
-        stack.push(5);
-        stack.push(3);
+        assert!(stack.push(5).is_ok());
+        assert!(stack.push(3).is_ok());
 
-        let result = apply_arithmetic_operation(&mut stack, "-");
+        let result = apply_arithmetic_operation(
+            &mut stack,
+            ArithmeticOp::Subtract,
+            OverflowPolicy::Wrap,
+            DivisionMode::Symmetric,
+        );
         assert_eq!(result, Ok(()));
         assert_eq!(stack.pop(), Some(2));
     }
@@ -37,10 +51,15 @@ mod arithmetic_operations_test {
     fn test_multiplication() {
         let mut stack = setup_stack();
 
-        stack.push(3);
-        stack.push(4);
+        assert!(stack.push(3).is_ok());
+        assert!(stack.push(4).is_ok());
 
-        let result = apply_arithmetic_operation(&mut stack, "*");
+        let result = apply_arithmetic_operation(
+            &mut stack,
+            ArithmeticOp::Multiply,
+            OverflowPolicy::Wrap,
+            DivisionMode::Symmetric,
+        );
         assert_eq!(result, Ok(()));
         assert_eq!(stack.pop(), Some(12));
     }
@@ -49,10 +68,15 @@ mod arithmetic_operations_test {
     fn test_division() {
         let mut stack = setup_stack();
 
-        stack.push(12);
-        stack.push(4);
+        assert!(stack.push(12).is_ok());
+        assert!(stack.push(4).is_ok());
 
-        let result = apply_arithmetic_operation(&mut stack, "/");
+        let result = apply_arithmetic_operation(
+            &mut stack,
+            ArithmeticOp::Divide,
+            OverflowPolicy::Wrap,
+            DivisionMode::Symmetric,
+        );
         assert_eq!(result, Ok(()));
         assert_eq!(stack.pop(), Some(3));
     }
@@ -61,67 +85,338 @@ mod arithmetic_operations_test {
     fn test_division_by_zero() {
         let mut stack = setup_stack();
 
-        stack.push(12);
-        stack.push(0);
+        assert!(stack.push(12).is_ok());
+        assert!(stack.push(0).is_ok());
 
-        let result = apply_arithmetic_operation(&mut stack, "/");
-        assert_eq!(result, Err("division-by-zero".to_string()));
+        let result = apply_arithmetic_operation(
+            &mut stack,
+            ArithmeticOp::Divide,
+            OverflowPolicy::Wrap,
+            DivisionMode::Symmetric,
+        );
+        assert_eq!(result, Err(ForthError::DivisionByZero));
     }
 
     #[test]
     fn test_not_enough_elements_for_addition() {
         let mut stack = setup_stack();
 
-        stack.push(2);
+        assert!(stack.push(2).is_ok());
 
-        let result = apply_arithmetic_operation(&mut stack, "+");
-        assert_eq!(result, Err("stack-underflow".to_string()));
+        let result = apply_arithmetic_operation(
+            &mut stack,
+            ArithmeticOp::Add,
+            OverflowPolicy::Wrap,
+            DivisionMode::Symmetric,
+        );
+        assert_eq!(result, Err(ForthError::StackUnderflow));
     }
 
     #[test]
     fn test_not_enough_elements_for_subtraction() {
         let mut stack = setup_stack();
 
-        stack.push(2);
+        assert!(stack.push(2).is_ok());
 
-        let result = apply_arithmetic_operation(&mut stack, "-");
-        assert_eq!(result, Err("stack-underflow".to_string()));
+        let result = apply_arithmetic_operation(
+            &mut stack,
+            ArithmeticOp::Subtract,
+            OverflowPolicy::Wrap,
+            DivisionMode::Symmetric,
+        );
+        assert_eq!(result, Err(ForthError::StackUnderflow));
     }
 
     #[test]
     fn test_not_enough_elements_for_multiplication() {
         let mut stack = setup_stack();
 
-        stack.push(2);
+        assert!(stack.push(2).is_ok());
 
-        let result = apply_arithmetic_operation(&mut stack, "*");
-        assert_eq!(result, Err("stack-underflow".to_string()));
+        let result = apply_arithmetic_operation(
+            &mut stack,
+            ArithmeticOp::Multiply,
+            OverflowPolicy::Wrap,
+            DivisionMode::Symmetric,
+        );
+        assert_eq!(result, Err(ForthError::StackUnderflow));
     }
 
     #[test]
     fn test_not_enough_elements_for_division() {
         let mut stack = setup_stack();
 
-        stack.push(2);
+        assert!(stack.push(2).is_ok());
 
-        let result = apply_arithmetic_operation(&mut stack, "/");
-        assert_eq!(result, Err("stack-underflow".to_string()));
+        let result = apply_arithmetic_operation(
+            &mut stack,
+            ArithmeticOp::Divide,
+            OverflowPolicy::Wrap,
+            DivisionMode::Symmetric,
+        );
+        assert_eq!(result, Err(ForthError::StackUnderflow));
     }
 
     #[test]
     fn test_chained_arithmetic_operations() {
         let mut stack = setup_stack();
 
-        stack.push(3);
-        stack.push(4);
-        assert_eq!(apply_arithmetic_operation(&mut stack, "+"), Ok(()));
+        assert!(stack.push(3).is_ok());
+        assert!(stack.push(4).is_ok());
+        assert_eq!(
+            apply_arithmetic_operation(
+                &mut stack,
+                ArithmeticOp::Add,
+                OverflowPolicy::Wrap,
+                DivisionMode::Symmetric
+            ),
+            Ok(())
+        );
+
+        assert!(stack.push(2).is_ok());
+        assert_eq!(
+            apply_arithmetic_operation(
+                &mut stack,
+                ArithmeticOp::Multiply,
+                OverflowPolicy::Wrap,
+                DivisionMode::Symmetric
+            ),
+            Ok(())
+        );
+
+        assert!(stack.push(5).is_ok());
+        assert_eq!(
+            apply_arithmetic_operation(
+                &mut stack,
+                ArithmeticOp::Subtract,
+                OverflowPolicy::Wrap,
+                DivisionMode::Symmetric
+            ),
+            Ok(())
+        );
 
-        stack.push(2);
-        assert_eq!(apply_arithmetic_operation(&mut stack, "*"), Ok(()));
+        assert_eq!(stack.pop(), Some(9));
+    }
 
-        stack.push(5);
-        assert_eq!(apply_arithmetic_operation(&mut stack, "-"), Ok(()));
+    fn apply_with_policy(
+        b: Cell,
+        a: Cell,
+        operator: ArithmeticOp,
+        overflow: OverflowPolicy,
+    ) -> Result<Option<Cell>, ForthError> {
+        let mut stack = setup_stack();
 
-        assert_eq!(stack.pop(), Some(9));
+        stack.push(b)?;
+        stack.push(a)?;
+        apply_arithmetic_operation(&mut stack, operator, overflow, DivisionMode::Symmetric)?;
+        Ok(stack.pop())
+    }
+
+    fn apply_all(
+        values: &[Cell],
+        operator: ArithmeticOp,
+        division: DivisionMode,
+    ) -> Result<Vec<Cell>, ForthError> {
+        let mut stack = setup_stack();
+
+        for value in values {
+            stack.push(*value)?;
+        }
+        apply_arithmetic_operation(&mut stack, operator, OverflowPolicy::Wrap, division)?;
+        Ok(stack.as_slice().to_vec())
+    }
+
+    #[test]
+    fn test_overflow_wraps_by_default() {
+        let overflow = OverflowPolicy::default();
+
+        assert_eq!(
+            apply_with_policy(Cell::from(i16::MAX), 1, ArithmeticOp::Add, overflow),
+            Ok(Some(Cell::from(i16::MIN)))
+        );
+        assert_eq!(
+            apply_with_policy(Cell::from(i16::MIN), 1, ArithmeticOp::Subtract, overflow),
+            Ok(Some(Cell::from(i16::MAX)))
+        );
+        assert_eq!(
+            apply_with_policy(256, 256, ArithmeticOp::Multiply, overflow),
+            Ok(Some(0))
+        );
+        assert_eq!(
+            apply_with_policy(Cell::from(i16::MIN), -1, ArithmeticOp::Divide, overflow),
+            Ok(Some(Cell::from(i16::MIN)))
+        );
+    }
+
+    #[test]
+    fn test_overflow_saturates() {
+        let overflow = OverflowPolicy::Saturate;
+
+        assert_eq!(
+            apply_with_policy(Cell::from(i16::MAX), 1, ArithmeticOp::Add, overflow),
+            Ok(Some(Cell::from(i16::MAX)))
+        );
+        assert_eq!(
+            apply_with_policy(Cell::from(i16::MIN), 1, ArithmeticOp::Subtract, overflow),
+            Ok(Some(Cell::from(i16::MIN)))
+        );
+        assert_eq!(
+            apply_with_policy(-256, 256, ArithmeticOp::Multiply, overflow),
+            Ok(Some(Cell::from(i16::MIN)))
+        );
+        assert_eq!(
+            apply_with_policy(Cell::from(i16::MIN), -1, ArithmeticOp::Divide, overflow),
+            Ok(Some(Cell::from(i16::MAX)))
+        );
+    }
+
+    #[test]
+    fn test_overflow_error() {
+        let overflow = OverflowPolicy::Error;
+
+        for (b, a, operator) in [
+            (Cell::from(i16::MAX), 1, ArithmeticOp::Add),
+            (Cell::from(i16::MIN), 1, ArithmeticOp::Subtract),
+            (256, 256, ArithmeticOp::Multiply),
+            (Cell::from(i16::MIN), -1, ArithmeticOp::Divide),
+        ] {
+            assert_eq!(
+                apply_with_policy(b, a, operator, overflow),
+                Err(ForthError::ArithmeticOverflow)
+            );
+        }
+        assert_eq!(
+            apply_with_policy(100, 200, ArithmeticOp::Multiply, overflow),
+            Ok(Some(20000))
+        );
+    }
+
+    #[test]
+    fn test_parse_overflow_policy() {
+        assert_eq!("wrap".parse(), Ok(OverflowPolicy::Wrap));
+        assert_eq!("SATURATE".parse(), Ok(OverflowPolicy::Saturate));
+        assert_eq!("error".parse(), Ok(OverflowPolicy::Error));
+        assert_eq!(
+            "clamp".parse::<OverflowPolicy>(),
+            Err(ForthError::InvalidArgument("clamp".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_unary_operations() {
+        let symmetric = DivisionMode::Symmetric;
+
+        assert_eq!(
+            apply_all(&[5], ArithmeticOp::Negate, symmetric),
+            Ok(vec![-5])
+        );
+        assert_eq!(apply_all(&[-5], ArithmeticOp::Abs, symmetric), Ok(vec![5]));
+        assert_eq!(
+            apply_all(&[5], ArithmeticOp::Increment, symmetric),
+            Ok(vec![6])
+        );
+        assert_eq!(
+            apply_all(&[5], ArithmeticOp::Decrement, symmetric),
+            Ok(vec![4])
+        );
+        assert_eq!(
+            apply_all(&[5], ArithmeticOp::Double, symmetric),
+            Ok(vec![10])
+        );
+        assert_eq!(
+            apply_all(&[-5], ArithmeticOp::Half, symmetric),
+            Ok(vec![-3])
+        );
+        assert_eq!(
+            apply_all(&[Cell::from(i16::MIN)], ArithmeticOp::Negate, symmetric),
+            Ok(vec![Cell::from(i16::MIN)])
+        );
+        assert_eq!(
+            apply_all(&[], ArithmeticOp::Abs, symmetric),
+            Err(ForthError::StackUnderflow)
+        );
+    }
+
+    #[test]
+    fn test_min_max() {
+        let symmetric = DivisionMode::Symmetric;
+
+        assert_eq!(
+            apply_all(&[3, -4], ArithmeticOp::Min, symmetric),
+            Ok(vec![-4])
+        );
+        assert_eq!(
+            apply_all(&[3, -4], ArithmeticOp::Max, symmetric),
+            Ok(vec![3])
+        );
+        assert_eq!(
+            apply_all(&[3], ArithmeticOp::Max, symmetric),
+            Err(ForthError::StackUnderflow)
+        );
+    }
+
+    #[test]
+    fn test_symmetric_division() {
+        let symmetric = DivisionMode::Symmetric;
+
+        assert_eq!(
+            apply_all(&[-7, 2], ArithmeticOp::Divide, symmetric),
+            Ok(vec![-3])
+        );
+        assert_eq!(
+            apply_all(&[-7, 2], ArithmeticOp::Mod, symmetric),
+            Ok(vec![-1])
+        );
+        assert_eq!(
+            apply_all(&[-7, 2], ArithmeticOp::DivMod, symmetric),
+            Ok(vec![-1, -3])
+        );
+        assert_eq!(
+            apply_all(&[7, -2], ArithmeticOp::DivMod, symmetric),
+            Ok(vec![1, -3])
+        );
+    }
+
+    #[test]
+    fn test_floored_division() {
+        let floored = DivisionMode::Floored;
+
+        assert_eq!(
+            apply_all(&[-7, 2], ArithmeticOp::Divide, floored),
+            Ok(vec![-4])
+        );
+        assert_eq!(apply_all(&[-7, 2], ArithmeticOp::Mod, floored), Ok(vec![1]));
+        assert_eq!(
+            apply_all(&[-7, 2], ArithmeticOp::DivMod, floored),
+            Ok(vec![1, -4])
+        );
+        assert_eq!(
+            apply_all(&[7, -2], ArithmeticOp::DivMod, floored),
+            Ok(vec![-1, -4])
+        );
+        assert_eq!(
+            apply_all(&[7, 2], ArithmeticOp::DivMod, floored),
+            Ok(vec![1, 3])
+        );
+    }
+
+    #[test]
+    fn test_modulo_by_zero() {
+        for operator in [ArithmeticOp::Mod, ArithmeticOp::DivMod] {
+            assert_eq!(
+                apply_all(&[7, 0], operator, DivisionMode::Floored),
+                Err(ForthError::DivisionByZero)
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_division_mode() {
+        assert_eq!("symmetric".parse(), Ok(DivisionMode::Symmetric));
+        assert_eq!("FLOORED".parse(), Ok(DivisionMode::Floored));
+        assert_eq!(
+            "euclid".parse::<DivisionMode>(),
+            Err(ForthError::InvalidArgument("euclid".to_string()))
+        );
     }
 }
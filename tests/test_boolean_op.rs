@@ -1,4 +1,6 @@
-use forth_interpreter::boolean_operations::apply_boolean_operation;
+use forth_interpreter::boolean_operations::{BooleanOp, LogicMode, apply_boolean_operation};
+use forth_interpreter::cell::{Cell, CellWidth};
+use forth_interpreter::errors::ForthError;
 use forth_interpreter::stack::Stack;
 
 #[cfg(test)]
@@ -13,10 +15,10 @@ mod boolean_operations_test {
     fn test_equal_is_true() {
         let mut stack = setup_stack();
 
-        stack.push(5);
-        stack.push(5);
+        assert!(stack.push(5).is_ok());
+        assert!(stack.push(5).is_ok());
 
-        let result = apply_boolean_operation(&mut stack, "=");
+        let result = apply_boolean_operation(&mut stack, BooleanOp::Equal, LogicMode::Logical);
         assert_eq!(result, Ok(()));
         assert_eq!(stack.pop(), Some(-1));
     }
@@ -25,10 +27,10 @@ mod boolean_operations_test {
     fn test_equal_is_false() {
         let mut stack = setup_stack();
 
-        stack.push(4);
-        stack.push(5);
+        assert!(stack.push(4).is_ok());
+        assert!(stack.push(5).is_ok());
 
-        let result = apply_boolean_operation(&mut stack, "=");
+        let result = apply_boolean_operation(&mut stack, BooleanOp::Equal, LogicMode::Logical);
         assert_eq!(result, Ok(()));
         assert_eq!(stack.pop(), Some(0));
     }
@@ -37,10 +39,10 @@ mod boolean_operations_test {
     fn test_lower_than_is_true() {
         let mut stack = setup_stack();
 
-        stack.push(3);
-        stack.push(5);
+        assert!(stack.push(3).is_ok());
+        assert!(stack.push(5).is_ok());
 
-        let result = apply_boolean_operation(&mut stack, "<");
+        let result = apply_boolean_operation(&mut stack, BooleanOp::Less, LogicMode::Logical);
         assert_eq!(result, Ok(()));
         assert_eq!(stack.pop(), Some(-1));
     }
@@ -49,10 +51,10 @@ mod boolean_operations_test {
     fn test_lower_than_is_false() {
         let mut stack = setup_stack();
 
-        stack.push(3);
-        stack.push(2);
+        assert!(stack.push(3).is_ok());
+        assert!(stack.push(2).is_ok());
 
-        let result = apply_boolean_operation(&mut stack, "<");
+        let result = apply_boolean_operation(&mut stack, BooleanOp::Less, LogicMode::Logical);
         assert_eq!(result, Ok(()));
         assert_eq!(stack.pop(), Some(0));
     }
@@ -61,10 +63,10 @@ mod boolean_operations_test {
     fn test_greater_than_is_true() {
         let mut stack = setup_stack();
 
-        stack.push(5);
-        stack.push(3);
+        assert!(stack.push(5).is_ok());
+        assert!(stack.push(3).is_ok());
 
-        let result = apply_boolean_operation(&mut stack, ">");
+        let result = apply_boolean_operation(&mut stack, BooleanOp::Greater, LogicMode::Logical);
         assert_eq!(result, Ok(()));
         assert_eq!(stack.pop(), Some(-1));
     }
@@ -73,10 +75,10 @@ mod boolean_operations_test {
     fn test_greater_than_is_false() {
         let mut stack = setup_stack();
 
-        stack.push(2);
-        stack.push(3);
+        assert!(stack.push(2).is_ok());
+        assert!(stack.push(3).is_ok());
 
-        let result = apply_boolean_operation(&mut stack, ">");
+        let result = apply_boolean_operation(&mut stack, BooleanOp::Greater, LogicMode::Logical);
         assert_eq!(result, Ok(()));
         assert_eq!(stack.pop(), Some(0));
     }
@@ -85,10 +87,10 @@ mod boolean_operations_test {
     fn test_true_and_true_is_true() {
         let mut stack = setup_stack();
 
-        stack.push(-1);
-        stack.push(-1);
+        assert!(stack.push(-1).is_ok());
+        assert!(stack.push(-1).is_ok());
 
-        let result = apply_boolean_operation(&mut stack, "AND");
+        let result = apply_boolean_operation(&mut stack, BooleanOp::And, LogicMode::Logical);
         assert_eq!(result, Ok(()));
         assert_eq!(stack.pop(), Some(-1));
     }
@@ -97,10 +99,10 @@ mod boolean_operations_test {
     fn test_true_and_false_is_false() {
         let mut stack = setup_stack();
 
-        stack.push(-1);
-        stack.push(0);
+        assert!(stack.push(-1).is_ok());
+        assert!(stack.push(0).is_ok());
 
-        let result = apply_boolean_operation(&mut stack, "AND");
+        let result = apply_boolean_operation(&mut stack, BooleanOp::And, LogicMode::Logical);
         assert_eq!(result, Ok(()));
         assert_eq!(stack.pop(), Some(0));
     }
@@ -109,10 +111,10 @@ mod boolean_operations_test {
     fn test_false_and_false_is_false() {
         let mut stack = setup_stack();
 
-        stack.push(-1);
-        stack.push(0);
+        assert!(stack.push(-1).is_ok());
+        assert!(stack.push(0).is_ok());
 
-        let result = apply_boolean_operation(&mut stack, "AND");
+        let result = apply_boolean_operation(&mut stack, BooleanOp::And, LogicMode::Logical);
         assert_eq!(result, Ok(()));
         assert_eq!(stack.pop(), Some(0));
     }
@@ -121,10 +123,10 @@ mod boolean_operations_test {
     fn test_true_or_true_is_true() {
         let mut stack = setup_stack();
 
-        stack.push(-1);
-        stack.push(-1);
+        assert!(stack.push(-1).is_ok());
+        assert!(stack.push(-1).is_ok());
 
-        let result = apply_boolean_operation(&mut stack, "OR");
+        let result = apply_boolean_operation(&mut stack, BooleanOp::Or, LogicMode::Logical);
         assert_eq!(result, Ok(()));
         assert_eq!(stack.pop(), Some(-1));
     }
@@ -133,10 +135,10 @@ mod boolean_operations_test {
     fn test_true_or_false_is_true() {
         let mut stack = setup_stack();
 
-        stack.push(-1);
-        stack.push(-1);
+        assert!(stack.push(-1).is_ok());
+        assert!(stack.push(-1).is_ok());
 
-        let result = apply_boolean_operation(&mut stack, "OR");
+        let result = apply_boolean_operation(&mut stack, BooleanOp::Or, LogicMode::Logical);
         assert_eq!(result, Ok(()));
         assert_eq!(stack.pop(), Some(-1));
     }
@@ -145,10 +147,10 @@ mod boolean_operations_test {
     fn test_false_or_false_is_false() {
         let mut stack = setup_stack();
 
-        stack.push(0);
-        stack.push(0);
+        assert!(stack.push(0).is_ok());
+        assert!(stack.push(0).is_ok());
 
-        let result = apply_boolean_operation(&mut stack, "OR");
+        let result = apply_boolean_operation(&mut stack, BooleanOp::Or, LogicMode::Logical);
         assert_eq!(result, Ok(()));
         assert_eq!(stack.pop(), Some(0));
     }
@@ -157,9 +159,9 @@ mod boolean_operations_test {
     fn test_not_false_is_true() {
         let mut stack = setup_stack();
 
-        stack.push(0);
+        assert!(stack.push(0).is_ok());
 
-        let result = apply_boolean_operation(&mut stack, "NOT");
+        let result = apply_boolean_operation(&mut stack, BooleanOp::Not, LogicMode::Logical);
         assert_eq!(result, Ok(()));
         assert_eq!(stack.pop(), Some(-1));
     }
@@ -168,9 +170,9 @@ mod boolean_operations_test {
     fn test_not_true_is_false() {
         let mut stack = setup_stack();
 
-        stack.push(-1);
+        assert!(stack.push(-1).is_ok());
 
-        let result = apply_boolean_operation(&mut stack, "NOT");
+        let result = apply_boolean_operation(&mut stack, BooleanOp::Not, LogicMode::Logical);
         assert_eq!(result, Ok(()));
         assert_eq!(stack.pop(), Some(0));
     }
@@ -179,56 +181,258 @@ mod boolean_operations_test {
     fn test_equal_not_enough_elements() {
         let mut stack = setup_stack();
 
-        let result = apply_boolean_operation(&mut stack, "=");
-        assert_eq!(result, Err("stack-underflow".to_string()));
+        let result = apply_boolean_operation(&mut stack, BooleanOp::Equal, LogicMode::Logical);
+        assert_eq!(result, Err(ForthError::StackUnderflow));
     }
 
     #[test]
     fn test_lower_than_not_enough_elements() {
         let mut stack = setup_stack();
 
-        let result = apply_boolean_operation(&mut stack, "<");
-        assert_eq!(result, Err("stack-underflow".to_string()));
+        let result = apply_boolean_operation(&mut stack, BooleanOp::Less, LogicMode::Logical);
+        assert_eq!(result, Err(ForthError::StackUnderflow));
     }
 
     #[test]
     fn test_greater_than_not_enough_elements() {
         let mut stack = setup_stack();
 
-        let result = apply_boolean_operation(&mut stack, ">");
-        assert_eq!(result, Err("stack-underflow".to_string()));
+        let result = apply_boolean_operation(&mut stack, BooleanOp::Greater, LogicMode::Logical);
+        assert_eq!(result, Err(ForthError::StackUnderflow));
     }
 
     #[test]
     fn test_and_not_enough_elements() {
         let mut stack = setup_stack();
 
-        let result = apply_boolean_operation(&mut stack, "AND");
-        assert_eq!(result, Err("stack-underflow".to_string()));
+        let result = apply_boolean_operation(&mut stack, BooleanOp::And, LogicMode::Logical);
+        assert_eq!(result, Err(ForthError::StackUnderflow));
     }
 
     #[test]
     fn test_or_not_enough_elements() {
         let mut stack = setup_stack();
 
-        let result = apply_boolean_operation(&mut stack, "OR");
-        assert_eq!(result, Err("stack-underflow".to_string()));
+        let result = apply_boolean_operation(&mut stack, BooleanOp::Or, LogicMode::Logical);
+        assert_eq!(result, Err(ForthError::StackUnderflow));
     }
 
     #[test]
     fn test_chained_boolean_operations() {
         let mut stack = setup_stack();
 
-        stack.push(5);
-        stack.push(5);
-        assert_eq!(apply_boolean_operation(&mut stack, "="), Ok(()));
+        assert!(stack.push(5).is_ok());
+        assert!(stack.push(5).is_ok());
+        assert_eq!(
+            apply_boolean_operation(&mut stack, BooleanOp::Equal, LogicMode::Logical),
+            Ok(())
+        );
+
+        assert!(stack.push(3).is_ok());
+        assert!(stack.push(2).is_ok());
+        assert_eq!(
+            apply_boolean_operation(&mut stack, BooleanOp::Greater, LogicMode::Logical),
+            Ok(())
+        );
+
+        assert_eq!(
+            apply_boolean_operation(&mut stack, BooleanOp::And, LogicMode::Logical),
+            Ok(())
+        );
 
-        stack.push(3);
-        stack.push(2);
-        assert_eq!(apply_boolean_operation(&mut stack, ">"), Ok(()));
+        assert_eq!(stack.pop(), Some(-1));
+    }
 
-        assert_eq!(apply_boolean_operation(&mut stack, "AND"), Ok(()));
+    fn apply_all(
+        values: &[Cell],
+        operator: BooleanOp,
+        logic: LogicMode,
+    ) -> Result<Vec<Cell>, ForthError> {
+        let mut stack = setup_stack();
 
-        assert_eq!(stack.pop(), Some(-1));
+        for value in values {
+            stack.push(*value)?;
+        }
+        apply_boolean_operation(&mut stack, operator, logic)?;
+        Ok(stack.as_slice().to_vec())
+    }
+
+    #[test]
+    fn test_bitwise_and_or() {
+        assert_eq!(
+            apply_all(&[1, 2], BooleanOp::And, LogicMode::Logical),
+            Ok(vec![-1])
+        );
+        assert_eq!(
+            apply_all(&[1, 2], BooleanOp::And, LogicMode::Bitwise),
+            Ok(vec![0])
+        );
+        assert_eq!(
+            apply_all(&[12, 10], BooleanOp::And, LogicMode::Bitwise),
+            Ok(vec![8])
+        );
+        assert_eq!(
+            apply_all(&[12, 10], BooleanOp::Or, LogicMode::Bitwise),
+            Ok(vec![14])
+        );
+        assert_eq!(
+            apply_all(&[1, 2], BooleanOp::Or, LogicMode::Logical),
+            Ok(vec![-1])
+        );
+    }
+
+    #[test]
+    fn test_xor_and_invert() {
+        assert_eq!(
+            apply_all(&[12, 10], BooleanOp::Xor, LogicMode::Logical),
+            Ok(vec![6])
+        );
+        assert_eq!(
+            apply_all(&[0], BooleanOp::Invert, LogicMode::Logical),
+            Ok(vec![-1])
+        );
+        assert_eq!(
+            apply_all(&[5], BooleanOp::Invert, LogicMode::Logical),
+            Ok(vec![-6])
+        );
+        assert_eq!(
+            apply_all(&[], BooleanOp::Invert, LogicMode::Logical),
+            Err(ForthError::StackUnderflow)
+        );
+    }
+
+    #[test]
+    fn test_shifts() {
+        assert_eq!(
+            apply_all(&[1, 4], BooleanOp::LeftShift, LogicMode::Logical),
+            Ok(vec![16])
+        );
+        assert_eq!(
+            apply_all(&[1, 15], BooleanOp::LeftShift, LogicMode::Logical),
+            Ok(vec![-32768])
+        );
+        assert_eq!(
+            apply_all(&[1, 16], BooleanOp::LeftShift, LogicMode::Logical),
+            Ok(vec![0])
+        );
+        assert_eq!(
+            apply_all(&[16, 2], BooleanOp::RightShift, LogicMode::Logical),
+            Ok(vec![4])
+        );
+        assert_eq!(
+            apply_all(&[-1, 1], BooleanOp::RightShift, LogicMode::Logical),
+            Ok(vec![32767])
+        );
+        assert_eq!(
+            apply_all(&[1], BooleanOp::LeftShift, LogicMode::Logical),
+            Err(ForthError::StackUnderflow)
+        );
+    }
+
+    #[test]
+    fn test_shift_uses_cell_width() {
+        let mut stack = Stack::with_cell_width(1024, CellWidth::Bits32);
+
+        assert!(stack.push(-1).is_ok());
+        assert!(stack.push(1).is_ok());
+        assert_eq!(
+            apply_boolean_operation(&mut stack, BooleanOp::RightShift, LogicMode::Logical),
+            Ok(())
+        );
+        assert_eq!(stack.pop(), Some(Cell::from(i32::MAX)));
+    }
+
+    #[test]
+    fn test_comparisons_with_zero() {
+        assert_eq!(
+            apply_all(&[0], BooleanOp::ZeroEqual, LogicMode::Logical),
+            Ok(vec![-1])
+        );
+        assert_eq!(
+            apply_all(&[3], BooleanOp::ZeroEqual, LogicMode::Logical),
+            Ok(vec![0])
+        );
+        assert_eq!(
+            apply_all(&[-3], BooleanOp::ZeroLess, LogicMode::Logical),
+            Ok(vec![-1])
+        );
+        assert_eq!(
+            apply_all(&[3], BooleanOp::ZeroLess, LogicMode::Logical),
+            Ok(vec![0])
+        );
+        assert_eq!(
+            apply_all(&[3], BooleanOp::ZeroGreater, LogicMode::Logical),
+            Ok(vec![-1])
+        );
+        assert_eq!(
+            apply_all(&[], BooleanOp::ZeroEqual, LogicMode::Logical),
+            Err(ForthError::StackUnderflow)
+        );
+    }
+
+    #[test]
+    fn test_not_equal_and_unsigned_comparisons() {
+        assert_eq!(
+            apply_all(&[1, 2], BooleanOp::NotEqual, LogicMode::Logical),
+            Ok(vec![-1])
+        );
+        assert_eq!(
+            apply_all(&[2, 2], BooleanOp::NotEqual, LogicMode::Logical),
+            Ok(vec![0])
+        );
+        assert_eq!(
+            apply_all(&[1, -1], BooleanOp::UnsignedLess, LogicMode::Logical),
+            Ok(vec![-1])
+        );
+        assert_eq!(
+            apply_all(&[1, -1], BooleanOp::Less, LogicMode::Logical),
+            Ok(vec![0])
+        );
+        assert_eq!(
+            apply_all(&[-1, 1], BooleanOp::UnsignedGreater, LogicMode::Logical),
+            Ok(vec![-1])
+        );
+    }
+
+    #[test]
+    fn test_within() {
+        assert_eq!(
+            apply_all(&[5, 1, 10], BooleanOp::Within, LogicMode::Logical),
+            Ok(vec![-1])
+        );
+        assert_eq!(
+            apply_all(&[1, 1, 10], BooleanOp::Within, LogicMode::Logical),
+            Ok(vec![-1])
+        );
+        assert_eq!(
+            apply_all(&[10, 1, 10], BooleanOp::Within, LogicMode::Logical),
+            Ok(vec![0])
+        );
+        assert_eq!(
+            apply_all(&[-5, -10, 0], BooleanOp::Within, LogicMode::Logical),
+            Ok(vec![-1])
+        );
+        assert_eq!(
+            apply_all(&[20, 10, 1], BooleanOp::Within, LogicMode::Logical),
+            Ok(vec![-1])
+        );
+        assert_eq!(
+            apply_all(&[5, 10, 1], BooleanOp::Within, LogicMode::Logical),
+            Ok(vec![0])
+        );
+        assert_eq!(
+            apply_all(&[1, 2], BooleanOp::Within, LogicMode::Logical),
+            Err(ForthError::StackUnderflow)
+        );
+    }
+
+    #[test]
+    fn test_parse_logic_mode() {
+        assert_eq!("logical".parse(), Ok(LogicMode::Logical));
+        assert_eq!("Bitwise".parse(), Ok(LogicMode::Bitwise));
+        assert_eq!(
+            "fuzzy".parse::<LogicMode>(),
+            Err(ForthError::InvalidArgument("fuzzy".to_string()))
+        );
     }
 }
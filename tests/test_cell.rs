@@ -0,0 +1,108 @@
+use forth_interpreter::arithmetic_operations::OverflowPolicy;
+use forth_interpreter::cell::CellWidth;
+use forth_interpreter::errors::ForthError;
+use forth_interpreter::interpreter::Interpreter;
+use forth_interpreter::output::OutputBuffer;
+use forth_interpreter::stack::Stack;
+
+#[cfg(test)]
+mod cell_test {
+    use super::*;
+
+    fn setup_interpreter(width: CellWidth) -> Interpreter {
+        Interpreter::builder().cell_width(width).build()
+    }
+
+    #[test]
+    fn test_cell_width_limits() {
+        assert_eq!(CellWidth::Bits16.min(), -32768);
+        assert_eq!(CellWidth::Bits16.max(), 32767);
+        assert_eq!(CellWidth::Bits32.max(), 2147483647);
+        assert_eq!(CellWidth::Bits64.min(), i64::MIN);
+        assert_eq!(CellWidth::Bits64.bytes(), 8);
+    }
+
+    #[test]
+    fn test_cell_width_wrap() {
+        assert_eq!(CellWidth::Bits16.wrap(32768), -32768);
+        assert_eq!(CellWidth::Bits16.wrap(65535), -1);
+        assert_eq!(CellWidth::Bits32.wrap(2147483648), -2147483648);
+        assert_eq!(CellWidth::Bits64.wrap(i128::from(i64::MAX) + 1), i64::MIN);
+    }
+
+    #[test]
+    fn test_parse_cell_width() {
+        assert_eq!("16".parse(), Ok(CellWidth::Bits16));
+        assert_eq!("64".parse(), Ok(CellWidth::Bits64));
+        assert_eq!(
+            "128".parse::<CellWidth>(),
+            Err(ForthError::InvalidArgument("128".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_literals_follow_cell_width() {
+        let mut forth = setup_interpreter(CellWidth::Bits16);
+        assert_eq!(
+            forth.eval("40000").map_err(ForthError::into_root),
+            Err(ForthError::UnknownWord("40000".to_string()))
+        );
+
+        let mut forth = setup_interpreter(CellWidth::Bits32);
+        assert!(forth.eval("40000 -2147483648").is_ok());
+        assert_eq!(forth.stack(), &[40000, -2147483648]);
+
+        let mut forth = setup_interpreter(CellWidth::Bits64);
+        assert!(forth.eval("9223372036854775807").is_ok());
+        assert_eq!(forth.stack(), &[i64::MAX]);
+    }
+
+    #[test]
+    fn test_arithmetic_wraps_at_cell_width() {
+        let mut forth = setup_interpreter(CellWidth::Bits32);
+
+        assert!(forth.eval("2147483647 1 + 100000 100000 *").is_ok());
+        assert_eq!(forth.stack(), &[-2147483648, 1410065408]);
+    }
+
+    #[test]
+    fn test_overflow_policy_follows_cell_width() {
+        let mut forth = Interpreter::builder()
+            .cell_width(CellWidth::Bits32)
+            .overflow_policy(OverflowPolicy::Saturate)
+            .build();
+
+        assert!(forth.eval("32767 1 + 2147483647 1 +").is_ok());
+        assert_eq!(forth.stack(), &[32768, 2147483647]);
+    }
+
+    #[test]
+    fn test_stack_size_in_bytes_follows_cell_width() {
+        let mut stack = Stack::with_cell_width(8, CellWidth::Bits32);
+
+        assert!(stack.push(1).is_ok());
+        assert!(stack.push(2).is_ok());
+        assert_eq!(stack.push(3), Err(ForthError::StackOverflow));
+
+        let mut forth = Interpreter::builder()
+            .stack_size(16)
+            .cell_width(CellWidth::Bits64)
+            .build();
+        assert_eq!(
+            forth.eval("1 2 3").map_err(ForthError::into_root),
+            Err(ForthError::StackOverflow)
+        );
+    }
+
+    #[test]
+    fn test_print_wide_values() {
+        let output = OutputBuffer::new();
+        let mut forth = Interpreter::builder()
+            .cell_width(CellWidth::Bits64)
+            .output(output.clone())
+            .build();
+
+        assert!(forth.eval("3000000000 DUP * .").is_ok());
+        assert_eq!(output.contents(), "9000000000000000000");
+    }
+}
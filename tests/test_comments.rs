@@ -0,0 +1,88 @@
+use forth_interpreter::cell::Cell;
+use forth_interpreter::errors::ForthError;
+use forth_interpreter::interpreter::Interpreter;
+use forth_interpreter::output::OutputBuffer;
+use forth_interpreter::tokenizer::{Location, Tokenizer};
+
+#[cfg(test)]
+mod comments_test {
+    use super::*;
+
+    fn run(input: &str) -> Result<Vec<Cell>, ForthError> {
+        let mut forth = Interpreter::builder().output(OutputBuffer::new()).build();
+        forth.eval(input).map_err(ForthError::into_root)?;
+        Ok(forth.stack().to_vec())
+    }
+
+    #[test]
+    fn test_tokenizer_skips_comments() {
+        let mut tokens = Tokenizer::new("1 ( uno ) 2 \\ resto de la línea\n3 (sin-espacio)");
+
+        assert_eq!(tokens.next(), Some("1"));
+        assert_eq!(tokens.next(), Some("2"));
+        assert_eq!(tokens.next(), Some("3"));
+        assert_eq!(tokens.source().location, Location { line: 2, column: 1 });
+        assert_eq!(tokens.next(), Some("(sin-espacio)"));
+        assert_eq!(tokens.next(), None);
+    }
+
+    #[test]
+    fn test_tokenizer_comment_spans_lines() {
+        let mut tokens = Tokenizer::new("( una\nlarga\nexplicación ) DUP");
+
+        assert_eq!(tokens.next(), Some("DUP"));
+        assert_eq!(
+            tokens.source().location,
+            Location {
+                line: 3,
+                column: 15
+            }
+        );
+    }
+
+    #[test]
+    fn test_unterminated_comment_should_fail() {
+        assert_eq!(run("1 2 ( 3 4"), Err(ForthError::UnterminatedComment));
+        assert_eq!(
+            run(": F ( n -- \n DUP ;"),
+            Err(ForthError::UnterminatedComment)
+        );
+
+        let mut forth = Interpreter::builder().output(OutputBuffer::new()).build();
+        let error = forth.eval("1 2\n  ( oops + .");
+        assert_eq!(
+            error.map_err(|error| match error {
+                ForthError::At(located) => located.source.location,
+                _ => Location::default(),
+            }),
+            Err(Location { line: 2, column: 3 })
+        );
+    }
+
+    #[test]
+    fn test_stack_comment_in_definition() {
+        assert_eq!(run(": SQUARE ( n -- n*n ) DUP * ;\n5 SQUARE"), Ok(vec![25]));
+    }
+
+    #[test]
+    fn test_line_comments() {
+        let input = "\\ Programa de ejemplo\n1 2 + \\ suma\n\\ 10 20\n3";
+        assert_eq!(run(input), Ok(vec![3, 3]));
+    }
+
+    #[test]
+    fn test_comments_inside_conditionals_and_loops() {
+        let input = ": SIGN ( n -- s )\n  0< IF ( negativo ) -1 \\ menor\n  ELSE ( no ) 1 THEN ;\n-5 SIGN 5 SIGN";
+        assert_eq!(run(input), Ok(vec![-1, 1]));
+        assert_eq!(run("0 3 0 DO ( i ) I + LOOP"), Ok(vec![3]));
+    }
+
+    #[test]
+    fn test_comments_are_kept_in_strings() {
+        let output = OutputBuffer::new();
+        let mut forth = Interpreter::builder().output(output.clone()).build();
+
+        assert!(forth.eval(".\" a ( b ) \\ c\"").is_ok());
+        assert_eq!(output.contents(), "a ( b ) \\ c");
+    }
+}
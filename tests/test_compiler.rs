@@ -0,0 +1,127 @@
+use forth_interpreter::cell::CellWidth;
+use forth_interpreter::compiler::{Instruction, Primitive, compile_definition, compile_item};
+use forth_interpreter::data_space::DataSpace;
+use forth_interpreter::errors::ForthError;
+use forth_interpreter::forth_basic_operations::ForthOp;
+use forth_interpreter::tokenizer::Tokenizer;
+use forth_interpreter::word_definitions::WordMap;
+
+#[cfg(test)]
+mod compiler_test {
+    use super::*;
+
+    fn compile(input: &str) -> Result<Vec<Instruction>, ForthError> {
+        let mut word_map = WordMap::new();
+        let mut data_space = DataSpace::new(1024);
+        let mut tokens = Tokenizer::new(input);
+        let first = tokens.next().unwrap_or_default();
+        compile_item(
+            first,
+            &mut tokens,
+            &mut word_map,
+            &mut data_space,
+            CellWidth::default(),
+        )
+        .map(|code| code.instructions)
+    }
+
+    #[test]
+    fn test_compile_literal_and_primitive() {
+        assert_eq!(compile("42"), Ok(vec![Instruction::Literal(42)]));
+        assert_eq!(
+            compile("dup"),
+            Ok(vec![Instruction::Primitive(Primitive::Forth(ForthOp::Dup))])
+        );
+    }
+
+    #[test]
+    fn test_compile_if_else_then_branches() {
+        let expected = vec![
+            Instruction::BranchIfZero(3),
+            Instruction::Literal(1),
+            Instruction::Branch(4),
+            Instruction::Literal(2),
+        ];
+        assert_eq!(compile("IF 1 ELSE 2 THEN"), Ok(expected));
+    }
+
+    #[test]
+    fn test_compile_do_loop_with_leave() {
+        let expected = vec![
+            Instruction::Do,
+            Instruction::LoopIndex(0),
+            Instruction::Leave(4),
+            Instruction::Loop(1),
+        ];
+        assert_eq!(compile("DO I LEAVE LOOP"), Ok(expected));
+    }
+
+    #[test]
+    fn test_compile_begin_while_repeat() {
+        let expected = vec![
+            Instruction::Primitive(Primitive::Forth(ForthOp::Dup)),
+            Instruction::BranchIfZero(4),
+            Instruction::Primitive(Primitive::Forth(ForthOp::Drop)),
+            Instruction::Branch(0),
+        ];
+        assert_eq!(compile("BEGIN DUP WHILE DROP REPEAT"), Ok(expected));
+    }
+
+    #[test]
+    fn test_compile_unknown_word_should_fail() {
+        assert_eq!(
+            compile("FOO"),
+            Err(ForthError::UnknownWord("FOO".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_compile_unmatched_then_should_fail() {
+        assert_eq!(
+            compile("THEN"),
+            Err(ForthError::UnmatchedControl("THEN".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_compile_leave_outside_do_should_fail() {
+        assert_eq!(
+            compile("BEGIN LEAVE AGAIN"),
+            Err(ForthError::OutsideLoop("LEAVE"))
+        );
+    }
+
+    #[test]
+    fn test_compile_definition_with_does() {
+        let mut word_map = WordMap::new();
+        let mut data_space = DataSpace::new(1024);
+        let mut tokens = Tokenizer::new("CREATE , DOES> @ ;");
+
+        let code = compile_definition(
+            &mut tokens,
+            &mut word_map,
+            &mut data_space,
+            CellWidth::default(),
+        )
+        .map(|code| code.instructions);
+        assert_eq!(code.as_ref().map(|code| code.len()), Ok(3));
+        assert!(matches!(code.as_deref(), Ok([.., Instruction::Does(_)])));
+    }
+
+    #[test]
+    fn test_compile_definition_with_unclosed_if_should_fail() {
+        let mut word_map = WordMap::new();
+        let mut data_space = DataSpace::new(1024);
+        let mut tokens = Tokenizer::new("IF 1 ;");
+
+        assert_eq!(
+            compile_definition(
+                &mut tokens,
+                &mut word_map,
+                &mut data_space,
+                CellWidth::default()
+            ),
+            Err(ForthError::MismatchedControl(";".to_string(), "'THEN'"))
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use forth_interpreter::errors::ForthError;
+use forth_interpreter::interpreter_state::InterpreterState;
+use forth_interpreter::program::execute_operation;
+use forth_interpreter::stack::Stack;
+
+#[cfg(test)]
+mod defining_words_test {
+    use super::*;
+
+    fn setup_stack() -> Stack {
+        Stack::new(128 * 1024)
+    }
+
+    fn run(stack: &mut Stack, input: &str) -> Result<(), ForthError> {
+        let mut state = InterpreterState::new(1024);
+        execute_operation(stack, input.to_string(), &mut state).map_err(ForthError::into_root)
+    }
+
+    #[test]
+    fn test_create_pushes_body_address() {
+        let mut stack = setup_stack();
+        assert_eq!(
+            run(
+                &mut stack,
+                "VARIABLE X CREATE TABLE 10 , 20 , TABLE 1 + @ TABLE"
+            ),
+            Ok(())
+        );
+        assert_eq!(stack.pop(), Some(1));
+        assert_eq!(stack.pop(), Some(20));
+    }
+
+    #[test]
+    fn test_does_defines_runtime_behaviour() {
+        let mut stack = setup_stack();
+        let input = ": CONST CREATE , DOES> @ ; 5 CONST FIVE 7 CONST SEVEN FIVE SEVEN";
+        assert_eq!(run(&mut stack, input), Ok(()));
+        assert_eq!(stack.pop(), Some(7));
+        assert_eq!(stack.pop(), Some(5));
+    }
+
+    #[test]
+    fn test_array_defining_word() {
+        let mut stack = setup_stack();
+        let input = ": ARRAY CREATE CELLS ALLOT DOES> SWAP CELLS + ; \
+                     3 ARRAY A 3 ARRAY B 11 1 A ! 22 1 B ! 1 A @ 1 B @";
+        assert_eq!(run(&mut stack, input), Ok(()));
+        assert_eq!(stack.pop(), Some(22));
+        assert_eq!(stack.pop(), Some(11));
+    }
+
+    #[test]
+    fn test_record_defining_word_with_offsets() {
+        let mut stack = setup_stack();
+        let input = ": FIELD CREATE , DOES> @ + ; 0 FIELD .X 1 FIELD .Y \
+                     CREATE POINT 3 , 4 , POINT .Y @ POINT .X @";
+        assert_eq!(run(&mut stack, input), Ok(()));
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), Some(4));
+    }
+
+    #[test]
+    fn test_to_body_of_created_word() {
+        let mut stack = setup_stack();
+        let input = "VARIABLE X CREATE TABLE 10 , ' TABLE >BODY TABLE =";
+        assert_eq!(run(&mut stack, input), Ok(()));
+        assert_eq!(stack.pop(), Some(-1));
+    }
+
+    #[test]
+    fn test_to_body_of_word_not_created_should_fail() {
+        let mut stack = setup_stack();
+        assert_eq!(
+            run(&mut stack, ": FOO 1 ; ' FOO >BODY"),
+            Err(ForthError::InvalidMemoryAddress)
+        );
+    }
+
+    #[test]
+    fn test_tick_unknown_word_should_fail() {
+        let mut stack = setup_stack();
+        assert_eq!(
+            run(&mut stack, "' NOPE"),
+            Err(ForthError::UnknownWord("NOPE".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_defining_word_without_name_should_fail() {
+        let mut stack = setup_stack();
+        assert_eq!(
+            run(&mut stack, ": CONST CREATE , DOES> @ ; 5 CONST"),
+            Err(ForthError::MissingWordName)
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use forth_interpreter::arithmetic_operations::{DivisionMode, OverflowPolicy};
+use forth_interpreter::cell::{Cell, CellWidth};
+use forth_interpreter::double_operations::{DoubleOp, apply_double_operation};
+use forth_interpreter::errors::ForthError;
+use forth_interpreter::interpreter::Interpreter;
+use forth_interpreter::output::OutputBuffer;
+use forth_interpreter::stack::Stack;
+
+#[cfg(test)]
+mod double_operations_test {
+    use super::*;
+
+    fn run(input: &str) -> Result<Vec<Cell>, ForthError> {
+        let mut forth = Interpreter::builder().output(OutputBuffer::new()).build();
+        forth.eval(input).map_err(ForthError::into_root)?;
+        Ok(forth.stack().to_vec())
+    }
+
+    fn run_output(input: &str) -> Result<String, ForthError> {
+        let output = OutputBuffer::new();
+        let mut forth = Interpreter::builder().output(output.clone()).build();
+        forth.eval(input).map_err(ForthError::into_root)?;
+        Ok(output.contents())
+    }
+
+    #[test]
+    fn test_double_literals_are_stored_as_pairs() {
+        assert_eq!(run("100000."), Ok(vec![-31072, 1]));
+        assert_eq!(run("-1."), Ok(vec![-1, -1]));
+        assert_eq!(run("5."), Ok(vec![5, 0]));
+        assert_eq!(
+            run("3000000000."),
+            Err(ForthError::UnknownWord("3000000000.".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_split_and_join_double() {
+        let width = CellWidth::Bits16;
+        assert_eq!(width.split(100000), (-31072, 1));
+        assert_eq!(width.join(-31072, 1), 100000);
+        assert_eq!(width.join(-1, -1), -1);
+        assert_eq!(CellWidth::Bits64.join(-1, 0), i128::from(u64::MAX));
+    }
+
+    #[test]
+    fn test_double_point() {
+        assert_eq!(run_output("100000. D."), Ok("100000".to_string()));
+        assert_eq!(run_output("-70000. D."), Ok("-70000".to_string()));
+        assert_eq!(run_output("1 D."), Err(ForthError::StackUnderflow));
+    }
+
+    #[test]
+    fn test_double_sum_and_subtraction() {
+        assert_eq!(run_output("70000. 70000. D+ D."), Ok("140000".to_string()));
+        assert_eq!(run_output("1. 100000. D- D."), Ok("-99999".to_string()));
+        assert_eq!(run("-1. 1. D+"), Ok(vec![0, 0]));
+    }
+
+    #[test]
+    fn test_double_negate() {
+        assert_eq!(run_output("100000. DNEGATE D."), Ok("-100000".to_string()));
+        assert_eq!(run("0. DNEGATE"), Ok(vec![0, 0]));
+    }
+
+    #[test]
+    fn test_mixed_multiplication() {
+        assert_eq!(run_output("1000 1000 M* D."), Ok("1000000".to_string()));
+        assert_eq!(run_output("-1000 1000 M* D."), Ok("-1000000".to_string()));
+        assert_eq!(run("-1 -1 UM*"), Ok(vec![1, -2]));
+    }
+
+    #[test]
+    fn test_unsigned_mixed_division() {
+        assert_eq!(run("100000. 7 UM/MOD"), Ok(vec![5, 14285]));
+        assert_eq!(run("-1 -2 UM* -1 UM/MOD"), Ok(vec![0, -2]));
+        assert_eq!(run("1. 0 UM/MOD"), Err(ForthError::DivisionByZero));
+    }
+
+    #[test]
+    fn test_unsigned_mixed_division_overflow() {
+        let mut stack = Stack::new(1024);
+
+        assert!(stack.push_double(1 << 20).is_ok());
+        assert!(stack.push(2).is_ok());
+        assert_eq!(
+            apply_double_operation(
+                &mut stack,
+                DoubleOp::UnsignedMixedDivMod,
+                OverflowPolicy::Error,
+                DivisionMode::Symmetric
+            ),
+            Err(ForthError::ArithmeticOverflow)
+        );
+    }
+
+    #[test]
+    fn test_scale_uses_double_intermediate() {
+        assert_eq!(run("30000 3 4 */"), Ok(vec![22500]));
+        assert_eq!(run("-30000 3 4 */"), Ok(vec![-22500]));
+        assert_eq!(run("7 3 2 */MOD"), Ok(vec![1, 10]));
+        assert_eq!(run("1 2 0 */"), Err(ForthError::DivisionByZero));
+        assert_eq!(run("1 2 */"), Err(ForthError::StackUnderflow));
+    }
+
+    #[test]
+    fn test_pair_stack_operations() {
+        assert_eq!(run("1 2 2DUP"), Ok(vec![1, 2, 1, 2]));
+        assert_eq!(run("1 2 3 2DROP"), Ok(vec![1]));
+        assert_eq!(run("1 2 3 4 2SWAP"), Ok(vec![3, 4, 1, 2]));
+        assert_eq!(run("1 2 3 4 2OVER"), Ok(vec![1, 2, 3, 4, 1, 2]));
+        assert_eq!(run("1 2 3 2SWAP"), Err(ForthError::StackUnderflow));
+    }
+
+    #[test]
+    fn test_double_underflow_keeps_stack() {
+        let mut stack = Stack::new(1024);
+
+        assert!(stack.push(1).is_ok());
+        assert!(stack.push(2).is_ok());
+        assert!(stack.push(3).is_ok());
+        assert_eq!(
+            apply_double_operation(
+                &mut stack,
+                DoubleOp::DoubleAdd,
+                OverflowPolicy::Wrap,
+                DivisionMode::Symmetric
+            ),
+            Err(ForthError::StackUnderflow)
+        );
+        assert_eq!(stack.as_slice(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_double_words_with_wide_cells() {
+        let output = OutputBuffer::new();
+        let mut forth = Interpreter::builder()
+            .cell_width(CellWidth::Bits64)
+            .output(output.clone())
+            .build();
+
+        assert!(forth.eval("9223372036854775807 2 M* D.").is_ok());
+        assert_eq!(output.contents(), "18446744073709551614");
+    }
+
+    #[test]
+    fn test_mixed_division() {
+        assert_eq!(run("-7. 2 FM/MOD"), Ok(vec![1, -4]));
+        assert_eq!(run("-7. 2 SM/REM"), Ok(vec![-1, -3]));
+        assert_eq!(run("100000. 7 SM/REM"), Ok(vec![5, 14285]));
+        assert_eq!(run("7. 0 FM/MOD"), Err(ForthError::DivisionByZero));
+        assert_eq!(run("7 2 SM/REM"), Err(ForthError::StackUnderflow));
+    }
+
+    #[test]
+    fn test_scale_follows_division_mode() {
+        let mut forth = Interpreter::builder()
+            .division_mode(DivisionMode::Floored)
+            .output(OutputBuffer::new())
+            .build();
+
+        assert!(forth.eval("-7 1 2 */MOD").is_ok());
+        assert_eq!(forth.stack(), &[1, -4]);
+    }
+}
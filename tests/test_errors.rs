@@ -0,0 +1,80 @@
+use forth_interpreter::errors::{ForthError, IoError};
+use forth_interpreter::file_handling::read_file;
+use std::error::Error;
+use std::io;
+
+#[cfg(test)]
+mod errors_test {
+    use super::*;
+
+    #[test]
+    fn test_runtime_errors_keep_their_messages() {
+        assert_eq!(ForthError::StackUnderflow.to_string(), "stack-underflow");
+        assert_eq!(ForthError::StackOverflow.to_string(), "stack-overflow");
+        assert_eq!(ForthError::DivisionByZero.to_string(), "division-by-zero");
+        assert_eq!(
+            ForthError::ArithmeticOverflow.to_string(),
+            "arithmetic-overflow"
+        );
+        assert_eq!(ForthError::InvalidWord.to_string(), "invalid-word");
+        assert_eq!(
+            ForthError::ReturnStackUnderflow.to_string(),
+            "return-stack-underflow"
+        );
+    }
+
+    #[test]
+    fn test_unknown_word_is_displayed_as_question_mark() {
+        let error = ForthError::UnknownWord("FOO".to_string());
+        assert_eq!(error.to_string(), "?");
+        assert_eq!(error, ForthError::UnknownWord("FOO".to_string()));
+    }
+
+    #[test]
+    fn test_unterminated_errors_keep_their_messages() {
+        assert_eq!(
+            ForthError::UnterminatedDefinition.to_string(),
+            "Error: Se esperaba ';' al final de la definición"
+        );
+        assert_eq!(
+            ForthError::UnterminatedString.to_string(),
+            "Error: cadena de texto sin comilla final"
+        );
+        assert_eq!(
+            ForthError::UnterminatedControl("'THEN'").to_string(),
+            "Error: Falta 'THEN' en la estructura de control"
+        );
+        assert_eq!(
+            ForthError::MismatchedControl(";".to_string(), "'THEN'").to_string(),
+            "Error: ';' antes de 'THEN' en la estructura de control"
+        );
+        assert_eq!(
+            ForthError::OutsideLoop("LEAVE").to_string(),
+            "Error: 'LEAVE' fuera de un 'DO'"
+        );
+    }
+
+    #[test]
+    fn test_io_error_has_source() {
+        let error = ForthError::Io(IoError::new(
+            "Error al guardar la pila en el archivo".to_string(),
+            io::Error::new(io::ErrorKind::PermissionDenied, "denegado"),
+        ));
+
+        assert_eq!(
+            error.to_string(),
+            "Error al guardar la pila en el archivo: denegado"
+        );
+        assert!(error.source().is_some());
+        assert!(ForthError::StackUnderflow.source().is_none());
+    }
+
+    #[test]
+    fn test_read_missing_file_should_fail_with_io_error() {
+        let result = read_file("no-existe.fth".to_string());
+        assert!(matches!(
+            result,
+            Err(ForthError::Io(ref error)) if error.kind() == io::ErrorKind::NotFound
+        ));
+    }
+}
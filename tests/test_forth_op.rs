@@ -1,4 +1,6 @@
-use forth_interpreter::forth_basic_operations::apply_forth_operation;
+use forth_interpreter::cell::Cell;
+use forth_interpreter::errors::ForthError;
+use forth_interpreter::forth_basic_operations::{ForthOp, apply_forth_operation};
 use forth_interpreter::stack::Stack;
 
 #[cfg(test)]
@@ -13,9 +15,9 @@ mod forth_operations_test {
     fn test_dup() {
         let mut stack = setup_stack();
 
-        stack.push(5);
+        assert!(stack.push(5).is_ok());
 
-        let result = apply_forth_operation(&mut stack, "DUP");
+        let result = apply_forth_operation(&mut stack, ForthOp::Dup, false);
         assert_eq!(result, Ok(()));
         assert_eq!(stack.pop(), Some(5));
         assert_eq!(stack.pop(), Some(5));
@@ -25,9 +27,9 @@ mod forth_operations_test {
     fn test_drop() {
         let mut stack = setup_stack();
 
-        stack.push(10);
+        assert!(stack.push(10).is_ok());
 
-        let result = apply_forth_operation(&mut stack, "DROP");
+        let result = apply_forth_operation(&mut stack, ForthOp::Drop, false);
         assert_eq!(result, Ok(()));
         assert_eq!(stack.pop(), None);
     }
@@ -36,10 +38,10 @@ mod forth_operations_test {
     fn test_swap() {
         let mut stack = setup_stack();
 
-        stack.push(1);
-        stack.push(2);
+        assert!(stack.push(1).is_ok());
+        assert!(stack.push(2).is_ok());
 
-        let result = apply_forth_operation(&mut stack, "SWAP");
+        let result = apply_forth_operation(&mut stack, ForthOp::Swap, false);
         assert_eq!(result, Ok(()));
         assert_eq!(stack.pop(), Some(1));
         assert_eq!(stack.pop(), Some(2));
@@ -49,10 +51,10 @@ mod forth_operations_test {
     fn test_over() {
         let mut stack = setup_stack();
 
-        stack.push(3);
-        stack.push(4);
+        assert!(stack.push(3).is_ok());
+        assert!(stack.push(4).is_ok());
 
-        let result = apply_forth_operation(&mut stack, "OVER");
+        let result = apply_forth_operation(&mut stack, ForthOp::Over, false);
         assert_eq!(result, Ok(()));
         assert_eq!(stack.pop(), Some(3));
         assert_eq!(stack.pop(), Some(4));
@@ -63,13 +65,28 @@ mod forth_operations_test {
     fn test_rot() {
         let mut stack = setup_stack();
 
-        stack.push(1);
-        stack.push(2);
-        stack.push(3);
-        stack.push(4);
-        stack.push(5);
+        assert!(stack.push(1).is_ok());
+        assert!(stack.push(2).is_ok());
+        assert!(stack.push(3).is_ok());
+        assert!(stack.push(4).is_ok());
 
-        let result = apply_forth_operation(&mut stack, "ROT");
+        let result = apply_forth_operation(&mut stack, ForthOp::Rot, false);
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(stack.as_slice(), &[1, 3, 4, 2]);
+    }
+
+    #[test]
+    fn test_legacy_rot() {
+        let mut stack = setup_stack();
+
+        assert!(stack.push(1).is_ok());
+        assert!(stack.push(2).is_ok());
+        assert!(stack.push(3).is_ok());
+        assert!(stack.push(4).is_ok());
+        assert!(stack.push(5).is_ok());
+
+        let result = apply_forth_operation(&mut stack, ForthOp::Rot, true);
 
         assert_eq!(result, Ok(()));
         assert_eq!(stack.pop(), Some(1)); // El elemento que estaba en la base ahora está en la cima
@@ -83,41 +100,179 @@ mod forth_operations_test {
     fn test_dup_not_enough_elements() {
         let mut stack = setup_stack();
 
-        let result = apply_forth_operation(&mut stack, "DUP");
-        assert_eq!(result, Err("stack-underflow".to_string()));
+        let result = apply_forth_operation(&mut stack, ForthOp::Dup, false);
+        assert_eq!(result, Err(ForthError::StackUnderflow));
     }
 
     #[test]
     fn test_drop_not_enough_elements() {
         let mut stack = setup_stack();
 
-        let result = apply_forth_operation(&mut stack, "DROP");
-        assert_eq!(result, Err("stack-underflow".to_string()));
+        let result = apply_forth_operation(&mut stack, ForthOp::Drop, false);
+        assert_eq!(result, Err(ForthError::StackUnderflow));
     }
 
     #[test]
     fn test_swap_not_enough_elements() {
         let mut stack = setup_stack();
 
-        stack.push(1);
+        assert!(stack.push(1).is_ok());
 
-        let result = apply_forth_operation(&mut stack, "SWAP");
-        assert_eq!(result, Err("stack-underflow".to_string()));
+        let result = apply_forth_operation(&mut stack, ForthOp::Swap, false);
+        assert_eq!(result, Err(ForthError::StackUnderflow));
     }
 
     #[test]
     fn test_over_not_enough_elements() {
         let mut stack = setup_stack();
 
-        let result = apply_forth_operation(&mut stack, "OVER");
-        assert_eq!(result, Err("stack-underflow".to_string()));
+        let result = apply_forth_operation(&mut stack, ForthOp::Over, false);
+        assert_eq!(result, Err(ForthError::StackUnderflow));
     }
 
     #[test]
     fn test_rot_not_enough_elements() {
         let mut stack = setup_stack();
 
-        let result = apply_forth_operation(&mut stack, "ROT");
-        assert_eq!(result, Err("stack-underflow".to_string()));
+        let result = apply_forth_operation(&mut stack, ForthOp::Rot, false);
+        assert_eq!(result, Err(ForthError::StackUnderflow));
+    }
+
+    #[test]
+    fn test_dup_on_full_stack_should_fail() {
+        let mut stack = Stack::new(2);
+
+        assert!(stack.push(5).is_ok());
+
+        let result = apply_forth_operation(&mut stack, ForthOp::Dup, false);
+        assert_eq!(result, Err(ForthError::StackOverflow));
+    }
+
+    #[test]
+    fn test_over_on_full_stack_should_fail() {
+        let mut stack = Stack::new(4);
+
+        assert!(stack.push(1).is_ok());
+        assert!(stack.push(2).is_ok());
+
+        let result = apply_forth_operation(&mut stack, ForthOp::Over, false);
+        assert_eq!(result, Err(ForthError::StackOverflow));
+    }
+
+    #[test]
+    fn test_push_on_full_stack_should_fail() {
+        let mut stack = Stack::new(2);
+
+        assert_eq!(stack.push(1), Ok(()));
+        assert_eq!(stack.push(2), Err(ForthError::StackOverflow));
+        assert_eq!(stack.len(), 1);
+    }
+
+    fn apply_all(values: &[Cell], operator: ForthOp) -> Result<Vec<Cell>, ForthError> {
+        let mut stack = setup_stack();
+
+        for value in values {
+            stack.push(*value)?;
+        }
+        apply_forth_operation(&mut stack, operator, false)?;
+        Ok(stack.as_slice().to_vec())
+    }
+
+    #[test]
+    fn test_rot_needs_three_elements() {
+        assert_eq!(
+            apply_all(&[1, 2], ForthOp::Rot),
+            Err(ForthError::StackUnderflow)
+        );
+        assert_eq!(
+            apply_all(&[1, 2], ForthOp::MinusRot),
+            Err(ForthError::StackUnderflow)
+        );
+    }
+
+    #[test]
+    fn test_reverse_rot() {
+        assert_eq!(apply_all(&[1, 2, 3], ForthOp::MinusRot), Ok(vec![3, 1, 2]));
+    }
+
+    #[test]
+    fn test_nip_and_tuck() {
+        assert_eq!(apply_all(&[1, 2], ForthOp::Nip), Ok(vec![2]));
+        assert_eq!(apply_all(&[1, 2], ForthOp::Tuck), Ok(vec![2, 1, 2]));
+        assert_eq!(
+            apply_all(&[1], ForthOp::Nip),
+            Err(ForthError::StackUnderflow)
+        );
+        assert_eq!(
+            apply_all(&[1], ForthOp::Tuck),
+            Err(ForthError::StackUnderflow)
+        );
+    }
+
+    #[test]
+    fn test_question_dup() {
+        assert_eq!(apply_all(&[5], ForthOp::QuestionDup), Ok(vec![5, 5]));
+        assert_eq!(apply_all(&[0], ForthOp::QuestionDup), Ok(vec![0]));
+        assert_eq!(
+            apply_all(&[], ForthOp::QuestionDup),
+            Err(ForthError::StackUnderflow)
+        );
+    }
+
+    #[test]
+    fn test_pick() {
+        assert_eq!(
+            apply_all(&[1, 2, 3, 0], ForthOp::Pick),
+            Ok(vec![1, 2, 3, 3])
+        );
+        assert_eq!(
+            apply_all(&[1, 2, 3, 2], ForthOp::Pick),
+            Ok(vec![1, 2, 3, 1])
+        );
+        assert_eq!(
+            apply_all(&[1, 2, 3, 3], ForthOp::Pick),
+            Err(ForthError::StackUnderflow)
+        );
+        assert_eq!(
+            apply_all(&[1, 2, -1], ForthOp::Pick),
+            Err(ForthError::StackUnderflow)
+        );
+    }
+
+    #[test]
+    fn test_roll() {
+        assert_eq!(
+            apply_all(&[1, 2, 3, 4, 3], ForthOp::Roll),
+            Ok(vec![2, 3, 4, 1])
+        );
+        assert_eq!(apply_all(&[1, 2, 1], ForthOp::Roll), Ok(vec![2, 1]));
+        assert_eq!(apply_all(&[1, 2, 0], ForthOp::Roll), Ok(vec![1, 2]));
+        assert_eq!(
+            apply_all(&[1, 2], ForthOp::Roll),
+            Err(ForthError::StackUnderflow)
+        );
+    }
+
+    #[test]
+    fn test_depth_and_clear() {
+        assert_eq!(apply_all(&[], ForthOp::Depth), Ok(vec![0]));
+        assert_eq!(apply_all(&[7, 8], ForthOp::Depth), Ok(vec![7, 8, 2]));
+        assert_eq!(apply_all(&[7, 8], ForthOp::Clear), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_stack_pick_and_roll() {
+        let mut stack = setup_stack();
+
+        assert!(stack.push(1).is_ok());
+        assert!(stack.push(2).is_ok());
+        assert!(stack.push(3).is_ok());
+
+        assert_eq!(stack.pick(0), Some(3));
+        assert_eq!(stack.pick(2), Some(1));
+        assert_eq!(stack.pick(3), None);
+        assert_eq!(stack.roll(3), Err(ForthError::StackUnderflow));
+        assert_eq!(stack.unroll(1), Ok(()));
+        assert_eq!(stack.as_slice(), &[1, 3, 2]);
     }
 }
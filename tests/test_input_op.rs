@@ -0,0 +1,167 @@
+use forth_interpreter::cell::Cell;
+use forth_interpreter::errors::ForthError;
+use forth_interpreter::input::{BlockingInput, InputBuffer, InputReader, InputSource};
+use forth_interpreter::interpreter::Interpreter;
+use forth_interpreter::output::OutputBuffer;
+use std::io::{self, Cursor, Read};
+
+#[cfg(test)]
+mod input_operations_test {
+    use super::*;
+
+    fn setup_interpreter(input: &InputBuffer, output: &OutputBuffer) -> Interpreter {
+        Interpreter::builder()
+            .input(input.clone())
+            .output(output.clone())
+            .build()
+    }
+
+    fn run(text: &str, program: &str) -> Result<Vec<Cell>, ForthError> {
+        let mut forth = setup_interpreter(&InputBuffer::from(text), &OutputBuffer::new());
+        forth.eval(program).map_err(ForthError::into_root)?;
+        Ok(forth.stack().to_vec())
+    }
+
+    /// Origen que falla si se lo lee, para comprobar que `KEY?` no lee por adelantado.
+    struct Unreadable;
+
+    impl Read for Unreadable {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("no se debe leer"))
+        }
+    }
+
+    impl InputReader for Unreadable {
+        fn is_ready(&mut self) -> io::Result<bool> {
+            Ok(false)
+        }
+    }
+
+    #[test]
+    fn test_input_source_polls_without_reading() {
+        let mut input = InputSource::new(Box::new(InputBuffer::from("ab")));
+
+        assert_eq!(input.has_byte(), Ok(true));
+        assert_eq!(input.next_byte(), Ok(Some(b'a')));
+        assert_eq!(input.next_byte(), Ok(Some(b'b')));
+        assert_eq!(input.has_byte(), Ok(false));
+        assert_eq!(input.next_byte(), Ok(None));
+
+        let mut input = InputSource::new(Box::new(Unreadable));
+        assert_eq!(input.has_byte(), Ok(false));
+    }
+
+    #[test]
+    fn test_input_readers_report_readiness() {
+        let mut cursor = Cursor::new("a");
+        assert!(cursor.is_ready().is_ok_and(|ready| ready));
+        assert!(cursor.read(&mut [0]).is_ok());
+        assert!(cursor.is_ready().is_ok_and(|ready| !ready));
+
+        let mut blocking = BlockingInput::new(&b"xy"[..]);
+        assert!(blocking.is_ready().is_ok_and(|ready| ready));
+        let mut text = String::new();
+        assert!(blocking.read_to_string(&mut text).is_ok());
+        assert_eq!(text, "xy");
+        assert!(blocking.is_ready().is_ok_and(|ready| !ready));
+    }
+
+    #[test]
+    fn test_boxed_input_can_be_plugged_in() {
+        let boxed: Box<dyn InputReader + Send> = Box::new(InputBuffer::from("z"));
+        let mut forth = Interpreter::builder()
+            .input(boxed)
+            .output(OutputBuffer::new())
+            .build();
+        assert!(forth.eval("KEY? KEY KEY?").is_ok());
+        assert_eq!(forth.stack(), &[-1, 122, 0]);
+    }
+
+    #[test]
+    fn test_key() {
+        assert_eq!(run("Hi", "KEY KEY"), Ok(vec![72, 105]));
+        assert_eq!(run("", "KEY"), Ok(vec![-1]));
+    }
+
+    #[test]
+    fn test_key_question() {
+        assert_eq!(run("x", "KEY? KEY KEY?"), Ok(vec![-1, 120, 0]));
+    }
+
+    #[test]
+    fn test_accept_reads_line() {
+        let input = InputBuffer::from("hola\nresto");
+        let output = OutputBuffer::new();
+        let mut forth = setup_interpreter(&input, &output);
+
+        assert!(forth.eval("HERE 20 ALLOT 20 ACCEPT").is_ok());
+        assert_eq!(forth.stack(), &[4]);
+        assert!(forth.eval("0 SWAP TYPE").is_ok());
+        assert_eq!(output.contents(), "hola");
+        assert_eq!(input.remaining(), "resto");
+    }
+
+    #[test]
+    fn test_accept_stops_at_limit_and_end_of_input() {
+        let input = InputBuffer::from("abcdef");
+        let mut forth = setup_interpreter(&input, &OutputBuffer::new());
+
+        assert!(forth.eval("HERE 10 ALLOT 3 ACCEPT").is_ok());
+        assert_eq!(forth.stack(), &[3]);
+        assert_eq!(input.remaining(), "def");
+        assert!(forth.eval("CLEAR 0 10 ACCEPT").is_ok());
+        assert_eq!(forth.stack(), &[3]);
+    }
+
+    #[test]
+    fn test_expect_sets_span() {
+        assert_eq!(
+            run("abc\n", "HERE 10 ALLOT 10 EXPECT SPAN @ 0 C@"),
+            Ok(vec![3, 97])
+        );
+    }
+
+    #[test]
+    fn test_accept_outside_data_space() {
+        assert_eq!(
+            run("abc", "0 10 ACCEPT"),
+            Err(ForthError::InvalidMemoryAddress)
+        );
+        assert_eq!(run("abc", "10 ACCEPT"), Err(ForthError::StackUnderflow));
+    }
+
+    #[test]
+    fn test_filter_program() {
+        let input = InputBuffer::from("forth");
+        let output = OutputBuffer::new();
+        let mut forth = setup_interpreter(&input, &output);
+        let program = ": UPPER BEGIN KEY DUP -1 <> WHILE 32 - EMIT REPEAT DROP ; UPPER";
+
+        assert!(forth.eval(program).is_ok());
+        assert_eq!(output.contents(), "FORTH");
+    }
+
+    #[test]
+    fn test_input_added_later() {
+        let input = InputBuffer::new();
+        let mut forth = setup_interpreter(&input, &OutputBuffer::new());
+
+        assert!(forth.eval("KEY? KEY").is_ok());
+        input.push_str("z");
+        assert!(forth.eval("KEY").is_ok());
+        assert_eq!(forth.stack(), &[0, -1, 122]);
+    }
+
+    #[test]
+    fn test_key_question_does_not_consume_input() {
+        let input = InputBuffer::new();
+        let mut forth = setup_interpreter(&input, &OutputBuffer::new());
+
+        assert!(forth.eval("KEY?").is_ok());
+        input.push_str("q");
+        assert!(forth.eval("KEY? KEY?").is_ok());
+        assert_eq!(input.remaining(), "q");
+        assert!(forth.eval("KEY").is_ok());
+        assert_eq!(forth.stack(), &[0, -1, -1, 113]);
+    }
+}
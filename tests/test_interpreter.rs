@@ -0,0 +1,120 @@
+use forth_interpreter::arithmetic_operations::OverflowPolicy;
+use forth_interpreter::errors::ForthError;
+use forth_interpreter::interpreter::Interpreter;
+use forth_interpreter::output::OutputBuffer;
+use std::thread;
+
+#[cfg(test)]
+mod interpreter_test {
+    use super::*;
+
+    #[test]
+    fn test_eval_keeps_stack_between_calls() {
+        let mut forth = Interpreter::new();
+
+        assert!(forth.eval("1 2").is_ok());
+        assert!(forth.eval("+ 10").is_ok());
+        assert_eq!(forth.stack(), &[3, 10]);
+    }
+
+    #[test]
+    fn test_definitions_persist_between_calls() {
+        let mut forth = Interpreter::new();
+
+        assert!(forth.eval(": SQUARE DUP * ;").is_ok());
+        assert!(forth.word_map().find("SQUARE").is_some());
+
+        assert!(forth.push(9).is_ok());
+        assert!(forth.eval("SQUARE").is_ok());
+        assert_eq!(forth.pop(), Some(81));
+        assert_eq!(forth.pop(), None);
+    }
+
+    #[test]
+    fn test_eval_error_keeps_stack() {
+        let mut forth = Interpreter::new();
+        let result = forth.eval("1 2 FOO");
+
+        assert_eq!(
+            result.map_err(ForthError::into_root),
+            Err(ForthError::UnknownWord("FOO".to_string()))
+        );
+        assert_eq!(forth.stack(), &[1, 2]);
+
+        forth.clear_stack();
+        assert!(forth.stack().is_empty());
+    }
+
+    #[test]
+    fn test_builder_sets_stack_size() {
+        let mut forth = Interpreter::builder().stack_size(4).build();
+
+        assert_eq!(
+            forth.eval("1 2 3").map_err(ForthError::into_root),
+            Err(ForthError::StackOverflow)
+        );
+        assert_eq!(forth.stack(), &[1, 2]);
+    }
+
+    #[test]
+    fn test_push_on_full_stack_should_fail() {
+        let mut forth = Interpreter::builder().stack_size(2).build();
+
+        assert!(forth.push(1).is_ok());
+        assert_eq!(forth.push(2), Err(ForthError::StackOverflow));
+        assert_eq!(forth.stack(), &[1]);
+    }
+
+    #[test]
+    fn test_builder_sets_return_stack_size() {
+        let mut forth = Interpreter::builder().return_stack_size(2).build();
+
+        assert_eq!(
+            forth.eval("1 2 >R >R").map_err(ForthError::into_root),
+            Err(ForthError::ReturnStackOverflow)
+        );
+    }
+
+    #[test]
+    fn test_eval_missing_file_should_fail() {
+        let mut forth = Interpreter::new();
+        assert!(matches!(
+            forth.eval_file("no-existe.fth"),
+            Err(ForthError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_builder_sets_overflow_policy() {
+        let mut forth = Interpreter::builder()
+            .overflow_policy(OverflowPolicy::Error)
+            .build();
+
+        assert_eq!(
+            forth.eval("32767 1 +").map_err(ForthError::into_root),
+            Err(ForthError::ArithmeticOverflow)
+        );
+        assert!(forth.eval("32767 -1 +").is_ok());
+        assert_eq!(forth.stack(), &[32766]);
+    }
+
+    fn assert_send<T: Send>() {}
+
+    #[test]
+    fn test_interpreter_is_send() {
+        assert_send::<Interpreter>();
+        assert_send::<OutputBuffer>();
+    }
+
+    #[test]
+    fn test_interpreter_runs_in_another_thread() {
+        let output = OutputBuffer::new();
+        let mut forth = Interpreter::builder().output(output.clone()).build();
+        assert!(forth.eval(": SQUARE DUP * ;").is_ok());
+
+        let worker = thread::spawn(move || forth.eval("7 SQUARE .").map(|()| forth.stack().len()));
+
+        assert!(matches!(worker.join(), Ok(Ok(0))));
+        assert_eq!(output.contents(), "49");
+    }
+}
@@ -0,0 +1,158 @@
+use forth_interpreter::cell::Cell;
+use forth_interpreter::errors::ForthError;
+use forth_interpreter::interpreter::Interpreter;
+
+#[cfg(test)]
+mod loop_operations_test {
+    use super::*;
+
+    fn run(input: &str) -> Result<Vec<Cell>, ForthError> {
+        let mut forth = Interpreter::builder().stack_size(128 * 1024).build();
+        forth.eval(input).map_err(ForthError::into_root)?;
+        Ok(forth.stack().to_vec())
+    }
+
+    #[test]
+    fn test_do_loop_pushes_indices() {
+        assert_eq!(run("3 0 DO I LOOP"), Ok(vec![0, 1, 2]));
+        assert_eq!(
+            run("3 0 DO I LOOP I"),
+            Err(ForthError::LoopIndexOutsideLoop)
+        );
+    }
+
+    #[test]
+    fn test_do_loop_runs_body_at_least_once() {
+        assert_eq!(run("6 5 DO I LOOP"), Ok(vec![5]));
+    }
+
+    #[test]
+    fn test_plus_loop_with_positive_step() {
+        assert_eq!(run("10 0 DO I 3 +LOOP"), Ok(vec![0, 3, 6, 9]));
+    }
+
+    #[test]
+    fn test_plus_loop_with_negative_step() {
+        assert_eq!(run("0 3 DO I -1 +LOOP"), Ok(vec![3, 2, 1, 0]));
+    }
+
+    #[test]
+    fn test_nested_loops_with_i_and_j() {
+        assert_eq!(
+            run("2 0 DO 2 0 DO J 10 * I + LOOP LOOP"),
+            Ok(vec![0, 1, 10, 11])
+        );
+    }
+
+    #[test]
+    fn test_leave_exits_innermost_loop() {
+        assert_eq!(
+            run("10 0 DO I DUP 3 = IF LEAVE THEN LOOP 99"),
+            Ok(vec![0, 1, 2, 3, 99])
+        );
+    }
+
+    #[test]
+    fn test_leave_in_nested_loop_keeps_outer_loop() {
+        assert_eq!(
+            run("2 0 DO 5 0 DO I 1 = IF LEAVE THEN J LOOP LOOP"),
+            Ok(vec![0, 1])
+        );
+    }
+
+    #[test]
+    fn test_loop_inside_word_definition() {
+        assert_eq!(
+            run(": SQUARES 0 DO I I * LOOP ; 4 SQUARES"),
+            Ok(vec![0, 1, 4, 9])
+        );
+    }
+
+    #[test]
+    fn test_unloop_stops_iteration() {
+        assert_eq!(run("10 0 DO I UNLOOP LOOP 7"), Ok(vec![0, 7]));
+    }
+
+    #[test]
+    fn test_missing_loop_should_fail() {
+        assert_eq!(
+            run("10 0 DO I"),
+            Err(ForthError::UnterminatedControl("'LOOP'"))
+        );
+    }
+
+    #[test]
+    fn test_do_without_arguments_should_fail() {
+        assert_eq!(run("1 DO I LOOP"), Err(ForthError::StackUnderflow));
+    }
+
+    #[test]
+    fn test_index_outside_loop_should_fail() {
+        assert!(run("I").is_err());
+        assert!(run("3 0 DO J LOOP").is_err());
+    }
+
+    #[test]
+    fn test_begin_until_repeats_until_true() {
+        assert_eq!(run("0 BEGIN 1 + DUP 5 = UNTIL"), Ok(vec![5]));
+    }
+
+    #[test]
+    fn test_begin_while_repeat_extracts_digits() {
+        assert_eq!(
+            run("123 BEGIN DUP WHILE DUP 10 / SWAP OVER 10 * - SWAP REPEAT DROP"),
+            Ok(vec![3, 2, 1])
+        );
+    }
+
+    #[test]
+    fn test_begin_while_skips_body_when_false() {
+        assert_eq!(run("0 BEGIN 0 WHILE 99 REPEAT"), Ok(vec![0]));
+    }
+
+    #[test]
+    fn test_begin_again_exits_with_leave() {
+        assert_eq!(
+            run("1 0 DO 0 BEGIN 1 + DUP 3 = IF LEAVE THEN AGAIN LOOP"),
+            Ok(vec![3])
+        );
+    }
+
+    #[test]
+    fn test_nested_begin_with_if_else() {
+        let input = "0 BEGIN DUP 2 < IF 10 ELSE 20 THEN SWAP 0 BEGIN 1 + DUP 2 = UNTIL DROP 1 + DUP 3 = UNTIL";
+        assert_eq!(run(input), Ok(vec![10, 10, 20, 3]));
+    }
+
+    #[test]
+    fn test_begin_inside_word_definition() {
+        assert_eq!(
+            run(": COUNTDOWN BEGIN DUP 1 - DUP 0 = UNTIL ; 3 COUNTDOWN"),
+            Ok(vec![3, 2, 1, 0])
+        );
+    }
+
+    #[test]
+    fn test_missing_until_should_fail() {
+        assert_eq!(
+            run("0 BEGIN 1 +"),
+            Err(ForthError::UnterminatedControl("'UNTIL' o 'REPEAT'"))
+        );
+    }
+
+    #[test]
+    fn test_missing_repeat_should_fail() {
+        assert_eq!(
+            run("0 BEGIN DUP WHILE 1 - UNTIL"),
+            Err(ForthError::MismatchedControl(
+                "UNTIL".to_string(),
+                "'REPEAT'"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_until_without_condition_should_fail() {
+        assert_eq!(run("BEGIN UNTIL"), Err(ForthError::StackUnderflow));
+    }
+}
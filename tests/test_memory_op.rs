@@ -0,0 +1,164 @@
+use forth_interpreter::cell::{Cell, CellWidth};
+use forth_interpreter::data_space::DataSpace;
+use forth_interpreter::errors::ForthError;
+use forth_interpreter::interpreter::Interpreter;
+use forth_interpreter::interpreter_state::InterpreterState;
+use forth_interpreter::output::OutputBuffer;
+use forth_interpreter::program::execute_operation;
+use forth_interpreter::stack::Stack;
+
+#[cfg(test)]
+mod memory_operations_test {
+    use super::*;
+
+    fn setup_stack() -> Stack {
+        Stack::new(128 * 1024)
+    }
+
+    fn run(stack: &mut Stack, input: &str) -> Result<(), ForthError> {
+        let mut state = InterpreterState::new(1024);
+        execute_operation(stack, input.to_string(), &mut state).map_err(ForthError::into_root)
+    }
+
+    #[test]
+    fn test_variable_store_and_fetch() {
+        let mut stack = setup_stack();
+        assert_eq!(run(&mut stack, "VARIABLE X 42 X ! X @"), Ok(()));
+        assert_eq!(stack.pop(), Some(42));
+    }
+
+    #[test]
+    fn test_variable_starts_at_zero() {
+        let mut stack = setup_stack();
+        assert_eq!(run(&mut stack, "VARIABLE X X @"), Ok(()));
+        assert_eq!(stack.pop(), Some(0));
+    }
+
+    #[test]
+    fn test_plus_store() {
+        let mut stack = setup_stack();
+        assert_eq!(run(&mut stack, "VARIABLE X 5 X ! 3 X +! X @"), Ok(()));
+        assert_eq!(stack.pop(), Some(8));
+    }
+
+    #[test]
+    fn test_variable_inside_word_definition() {
+        let mut stack = setup_stack();
+        let input = "VARIABLE COUNTER : BUMP 1 COUNTER +! ; BUMP BUMP BUMP COUNTER @";
+        assert_eq!(run(&mut stack, input), Ok(()));
+        assert_eq!(stack.pop(), Some(3));
+    }
+
+    #[test]
+    fn test_constant() {
+        let mut stack = setup_stack();
+        assert_eq!(run(&mut stack, "10 CONSTANT TEN TEN TEN +"), Ok(()));
+        assert_eq!(stack.pop(), Some(20));
+    }
+
+    #[test]
+    fn test_value_and_to() {
+        let mut stack = setup_stack();
+        assert_eq!(run(&mut stack, "7 VALUE V V 9 TO V V"), Ok(()));
+        assert_eq!(stack.pop(), Some(9));
+        assert_eq!(stack.pop(), Some(7));
+    }
+
+    #[test]
+    fn test_to_inside_word_definition() {
+        let mut stack = setup_stack();
+        assert_eq!(run(&mut stack, "0 VALUE V : SET TO V ; 5 SET V"), Ok(()));
+        assert_eq!(stack.pop(), Some(5));
+    }
+
+    #[test]
+    fn test_to_non_value_should_fail() {
+        let mut stack = setup_stack();
+        assert_eq!(
+            run(&mut stack, "VARIABLE X 1 TO X"),
+            Err(ForthError::NotAValue("X".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_char_store_and_fetch_keep_low_byte() {
+        let mut stack = setup_stack();
+        assert_eq!(run(&mut stack, "VARIABLE X 322 X C! X @ X C@"), Ok(()));
+        assert_eq!(stack.pop(), Some(66));
+        assert_eq!(stack.pop(), Some(66));
+    }
+
+    #[test]
+    fn test_here_allot_and_comma() {
+        let mut stack = setup_stack();
+        let input = "HERE 3 CELLS ALLOT HERE 11 , 22 , HERE";
+        assert_eq!(run(&mut stack, input), Ok(()));
+        assert_eq!(stack.pop(), Some(5));
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), Some(0));
+    }
+
+    #[test]
+    fn test_array_with_allot() {
+        let mut stack = setup_stack();
+        let input = "VARIABLE ARR 2 CELLS ALLOT 30 ARR 2 CELLS + ! ARR 2 CELLS + @";
+        assert_eq!(run(&mut stack, input), Ok(()));
+        assert_eq!(stack.pop(), Some(30));
+    }
+
+    #[test]
+    fn test_fetch_out_of_range_should_fail() {
+        let mut stack = setup_stack();
+        assert_eq!(
+            run(&mut stack, "VARIABLE X X 1 + @"),
+            Err(ForthError::InvalidMemoryAddress)
+        );
+        assert_eq!(
+            run(&mut stack, "5 -1 !"),
+            Err(ForthError::InvalidMemoryAddress)
+        );
+    }
+
+    #[test]
+    fn test_store_without_arguments_should_fail() {
+        let mut stack = setup_stack();
+        assert_eq!(
+            run(&mut stack, "VARIABLE X X !"),
+            Err(ForthError::StackUnderflow)
+        );
+    }
+
+    #[test]
+    fn test_allot_beyond_capacity_should_fail() {
+        let mut data_space = DataSpace::new(4);
+        assert_eq!(data_space.allot(4), Ok(()));
+        assert_eq!(data_space.allot(1), Err(ForthError::DataSpaceOverflow));
+        assert_eq!(data_space.allot(-5), Err(ForthError::InvalidMemoryAddress));
+    }
+
+    #[test]
+    fn test_allot_extreme_counts_should_fail() {
+        let mut data_space = DataSpace::new(4);
+        assert_eq!(data_space.allot(1), Ok(()));
+        assert_eq!(
+            data_space.allot(Cell::MAX),
+            Err(ForthError::DataSpaceOverflow)
+        );
+        assert_eq!(
+            data_space.allot(Cell::MIN),
+            Err(ForthError::InvalidMemoryAddress)
+        );
+        assert_eq!(data_space.here(), 1);
+
+        let mut forth = Interpreter::builder()
+            .cell_width(CellWidth::Bits64)
+            .output(OutputBuffer::new())
+            .build();
+        assert_eq!(
+            forth
+                .eval("VARIABLE X 9223372036854775807 ALLOT")
+                .map_err(ForthError::into_root),
+            Err(ForthError::DataSpaceOverflow)
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use forth_interpreter::cell::{Cell, CellWidth, format_number, radix};
+use forth_interpreter::errors::ForthError;
+use forth_interpreter::interpreter::Interpreter;
+use forth_interpreter::output::OutputBuffer;
+
+#[cfg(test)]
+mod number_base_test {
+    use super::*;
+
+    fn run(input: &str) -> Result<Vec<Cell>, ForthError> {
+        let mut forth = Interpreter::builder().output(OutputBuffer::new()).build();
+        forth.eval(input).map_err(ForthError::into_root)?;
+        Ok(forth.stack().to_vec())
+    }
+
+    fn run_output(input: &str) -> Result<String, ForthError> {
+        let output = OutputBuffer::new();
+        let mut forth = Interpreter::builder().output(output.clone()).build();
+        forth.eval(input).map_err(ForthError::into_root)?;
+        Ok(output.contents())
+    }
+
+    #[test]
+    fn test_parse_in_base() {
+        let width = CellWidth::Bits16;
+
+        assert_eq!(width.parse("ff", 16), Some(255));
+        assert_eq!(width.parse("-FF", 16), Some(-255));
+        assert_eq!(width.parse("1010", 2), Some(10));
+        assert_eq!(width.parse("12", 2), None);
+        assert_eq!(width.parse("FFFF", 16), None);
+        assert_eq!(width.parse("-", 10), None);
+    }
+
+    #[test]
+    fn test_parse_prefixes_and_characters() {
+        let width = CellWidth::Bits16;
+
+        assert_eq!(width.parse("$ff", 10), Some(255));
+        assert_eq!(width.parse("#10", 16), Some(10));
+        assert_eq!(width.parse("%1010", 16), Some(10));
+        assert_eq!(width.parse("$-10", 10), Some(-16));
+        assert_eq!(width.parse("'A'", 16), Some(65));
+        assert_eq!(width.parse("'a'", 10), Some(97));
+        assert_eq!(width.parse("$", 10), None);
+        assert_eq!(width.parse("'AB'", 10), None);
+        assert_eq!(width.parse_double("$10000.", 10), Some(65536));
+    }
+
+    #[test]
+    fn test_leading_plus_sign() {
+        let width = CellWidth::Bits16;
+
+        assert_eq!(width.parse("+5", 10), Some(5));
+        assert_eq!(width.parse("$+10", 10), Some(16));
+        assert_eq!(width.parse("+", 10), None);
+        assert_eq!(width.parse("+-5", 10), None);
+        assert_eq!(width.parse_double("+70000.", 10), Some(70000));
+        assert_eq!(run("2 +5 +"), Ok(vec![7]));
+    }
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number(255, 16), "FF");
+        assert_eq!(format_number(-255, 16), "-FF");
+        assert_eq!(format_number(0, 2), "0");
+        assert_eq!(format_number(10, 2), "1010");
+        assert_eq!(format_number(i128::MIN, 10), i128::MIN.to_string());
+    }
+
+    #[test]
+    fn test_radix_range() {
+        assert_eq!(radix(16), Ok(16));
+        assert_eq!(radix(36), Ok(36));
+        assert_eq!(radix(1), Err(ForthError::InvalidArgument("1".to_string())));
+        assert_eq!(
+            radix(37),
+            Err(ForthError::InvalidArgument("37".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_base_words() {
+        assert_eq!(run("BASE @"), Ok(vec![10]));
+        assert_eq!(run("HEX BASE @ DECIMAL"), Ok(vec![16]));
+        assert_eq!(run("HEX FF DECIMAL 10"), Ok(vec![255, 10]));
+        assert_eq!(run("BINARY 1010 OCTAL 17"), Ok(vec![10, 15]));
+        assert_eq!(run("16 BASE ! ff"), Ok(vec![255]));
+    }
+
+    #[test]
+    fn test_base_does_not_use_data_space() {
+        assert_eq!(run("HERE HEX HERE"), Ok(vec![0, 0]));
+    }
+
+    #[test]
+    fn test_prefixes_ignore_base() {
+        assert_eq!(run("HEX #10 $10 %10 'A'"), Ok(vec![10, 16, 2, 65]));
+        assert_eq!(
+            run("DECIMAL 1F"),
+            Err(ForthError::UnknownWord("1F".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_print_in_base() {
+        assert_eq!(run_output("HEX FF ."), Ok("FF".to_string()));
+        assert_eq!(run_output("255 HEX -1 . ."), Ok("-1FF".to_string()));
+        assert_eq!(run_output("5 BINARY ."), Ok("101".to_string()));
+        assert_eq!(run_output("$10000. HEX D."), Ok("10000".to_string()));
+        assert_eq!(run_output("10 11 HEX .S"), Ok("<2> A B ".to_string()));
+    }
+
+    #[test]
+    fn test_invalid_base() {
+        assert_eq!(
+            run_output("1 BASE ! 5 ."),
+            Err(ForthError::InvalidArgument("1".to_string()))
+        );
+        assert_eq!(run("0 BASE ! DECIMAL 5"), Ok(vec![5]));
+    }
+
+    #[test]
+    fn test_base_is_read_when_compiling_definitions() {
+        assert_eq!(run(": X 10 ; HEX X 10"), Ok(vec![10, 16]));
+    }
+}
@@ -0,0 +1,96 @@
+use forth_interpreter::cell::DECIMAL_BASE;
+use forth_interpreter::interpreter::Interpreter;
+use forth_interpreter::output::OutputBuffer;
+use forth_interpreter::output_operations::{OutputOp, apply_output_operation};
+use forth_interpreter::stack::Stack;
+
+#[cfg(test)]
+mod output_operations_test {
+    use super::*;
+
+    fn setup_interpreter(output: &OutputBuffer) -> Interpreter {
+        Interpreter::builder()
+            .stack_size(128 * 1024)
+            .output(output.clone())
+            .build()
+    }
+
+    #[test]
+    fn test_dot_writes_to_output() {
+        let mut stack = Stack::new(128 * 1024);
+        let mut output = OutputBuffer::new();
+
+        assert!(stack.push(42).is_ok());
+
+        let result = apply_output_operation(
+            &mut stack,
+            OutputOp::Point,
+            &mut output,
+            DECIMAL_BASE,
+            false,
+        );
+        assert!(result.is_ok());
+        assert_eq!(output.contents(), "42");
+    }
+
+    #[test]
+    fn test_emit_and_cr_write_to_output() {
+        let output = OutputBuffer::new();
+        let mut forth = setup_interpreter(&output);
+
+        assert!(forth.eval("65 EMIT CR").is_ok());
+        assert_eq!(output.contents(), "A\n");
+    }
+
+    #[test]
+    fn test_print_string_writes_to_output() {
+        let output = OutputBuffer::new();
+        let mut forth = setup_interpreter(&output);
+
+        assert!(forth.eval(": GREET .\" Hola mundo\" ; GREET").is_ok());
+        assert_eq!(output.contents(), "Hola mundo");
+    }
+
+    #[test]
+    fn test_stack_overflow_aborts_before_later_output() {
+        let output = OutputBuffer::new();
+        let mut forth = Interpreter::builder()
+            .stack_size(4)
+            .output(output.clone())
+            .build();
+
+        assert!(forth.eval("1 2 3 .").is_err());
+        assert_eq!(output.contents(), "");
+        assert_eq!(forth.stack(), &[1, 2]);
+    }
+
+    #[test]
+    fn test_output_buffer_clear() {
+        let output = OutputBuffer::new();
+        let mut forth = setup_interpreter(&output);
+
+        assert!(forth.eval("1 .").is_ok());
+        output.clear();
+        assert!(forth.eval("2 .").is_ok());
+        assert_eq!(output.contents(), "2");
+    }
+
+    #[test]
+    fn test_print_stack_keeps_stack() {
+        let output = OutputBuffer::new();
+        let mut forth = setup_interpreter(&output);
+
+        assert!(forth.eval("1 2 3 .S").is_ok());
+        assert_eq!(output.contents(), "<3> 1 2 3 ");
+        assert_eq!(forth.stack(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_print_empty_stack() {
+        let output = OutputBuffer::new();
+        let mut forth = setup_interpreter(&output);
+
+        assert!(forth.eval(".S").is_ok());
+        assert_eq!(output.contents(), "<0> ");
+    }
+}
@@ -0,0 +1,125 @@
+use forth_interpreter::cell::CellWidth;
+use forth_interpreter::errors::ForthError;
+use forth_interpreter::interpreter::Interpreter;
+use forth_interpreter::output::OutputBuffer;
+
+#[cfg(test)]
+mod pictured_output_test {
+    use super::*;
+
+    fn run_output(input: &str) -> Result<String, ForthError> {
+        let output = OutputBuffer::new();
+        let mut forth = Interpreter::builder().output(output.clone()).build();
+        forth.eval(input).map_err(ForthError::into_root)?;
+        Ok(output.contents())
+    }
+
+    #[test]
+    fn test_pictured_number() {
+        assert_eq!(run_output("1234. <# #S #> TYPE"), Ok("1234".to_string()));
+        assert_eq!(run_output("0. <# #S #> TYPE"), Ok("0".to_string()));
+        assert_eq!(
+            run_output("1234. <# # # '.' HOLD #S #> TYPE"),
+            Ok("12.34".to_string())
+        );
+        assert_eq!(
+            run_output("255. HEX <# #S '$' HOLD #> TYPE"),
+            Ok("$FF".to_string())
+        );
+    }
+
+    #[test]
+    fn test_pictured_signed_number() {
+        let input = ": SIGNED ( n -- ) DUP ABS 0 <# #S ROT SIGN #> TYPE ; -42 SIGNED 7 SIGNED";
+        assert_eq!(run_output(input), Ok("-427".to_string()));
+    }
+
+    #[test]
+    fn test_pictured_leaves_address_and_length() {
+        let mut forth = Interpreter::builder().output(OutputBuffer::new()).build();
+
+        assert!(forth.eval("123. <# #S #> SWAP DROP").is_ok());
+        assert_eq!(forth.stack(), &[3]);
+        assert!(forth.eval("CLEAR 5. <# # #> DROP C@").is_ok());
+        assert_eq!(forth.stack(), &[53]);
+    }
+
+    #[test]
+    fn test_pictured_number_is_unsigned() {
+        let output = OutputBuffer::new();
+        let mut forth = Interpreter::builder()
+            .cell_width(CellWidth::Bits16)
+            .output(output.clone())
+            .build();
+
+        assert!(forth.eval("-1. <# #S #> TYPE").is_ok());
+        assert_eq!(output.contents(), "4294967295");
+    }
+
+    #[test]
+    fn test_pictured_output_overflow() {
+        assert_eq!(
+            run_output("<# 300 0 DO 'x' HOLD LOOP"),
+            Err(ForthError::PicturedOutputOverflow)
+        );
+        assert_eq!(run_output("<# #"), Err(ForthError::StackUnderflow));
+    }
+
+    #[test]
+    fn test_unsigned_point() {
+        assert_eq!(run_output("-1 U."), Ok("65535".to_string()));
+        assert_eq!(run_output("10 U."), Ok("10".to_string()));
+        assert_eq!(run_output("-1 HEX U."), Ok("FFFF".to_string()));
+    }
+
+    #[test]
+    fn test_right_aligned_numbers() {
+        assert_eq!(run_output("42 5 .R"), Ok("   42".to_string()));
+        assert_eq!(run_output("-42 5 .R"), Ok("  -42".to_string()));
+        assert_eq!(run_output("12345 2 .R"), Ok("12345".to_string()));
+        assert_eq!(run_output("-1 6 U.R"), Ok(" 65535".to_string()));
+        assert_eq!(run_output("100000. 8 D.R"), Ok("  100000".to_string()));
+        assert_eq!(run_output("5 .R"), Err(ForthError::StackUnderflow));
+    }
+
+    #[test]
+    fn test_aligned_table() {
+        let input = ": ROW ( a b -- ) SWAP 4 .R 6 .R CR ; 1 10 ROW 22 300 ROW";
+        assert_eq!(
+            run_output(input),
+            Ok("   1    10\n  22   300\n".to_string())
+        );
+    }
+
+    #[test]
+    fn test_spaces_and_blank() {
+        assert_eq!(run_output("1 . SPACE 2 ."), Ok("1 2".to_string()));
+        assert_eq!(run_output("3 SPACES"), Ok("   ".to_string()));
+        assert_eq!(run_output("-3 SPACES 0 SPACES"), Ok(String::new()));
+        assert_eq!(run_output("BL 1 . EMIT"), Ok("1 ".to_string()));
+    }
+
+    #[test]
+    fn test_large_and_negative_widths() {
+        assert_eq!(run_output("1 -5 .R -2 -3 U.R"), Ok("165534".to_string()));
+
+        let output = OutputBuffer::new();
+        let mut forth = Interpreter::builder()
+            .cell_width(CellWidth::Bits32)
+            .output(output.clone())
+            .build();
+        assert!(forth.eval("100000 SPACES").is_ok());
+        assert_eq!(output.contents(), " ".repeat(100000));
+
+        output.clear();
+        assert!(forth.eval("1 100000 .R").is_ok());
+        assert_eq!(output.contents(), format!("{}1", " ".repeat(99999)));
+    }
+
+    #[test]
+    fn test_question_prints_cell() {
+        assert_eq!(run_output("VARIABLE X 42 X ! X ?"), Ok("42".to_string()));
+        assert_eq!(run_output("HEX BASE ?"), Ok("10".to_string()));
+        assert_eq!(run_output("5 ?"), Err(ForthError::InvalidMemoryAddress));
+    }
+}
@@ -0,0 +1,120 @@
+use forth_interpreter::cell::Cell;
+use forth_interpreter::interpreter::Interpreter;
+use forth_interpreter::output::OutputBuffer;
+use forth_interpreter::repl::repl;
+
+#[cfg(test)]
+mod repl_test {
+    use super::*;
+
+    fn setup_interpreter(output: &OutputBuffer) -> Interpreter {
+        Interpreter::builder()
+            .stack_size(128 * 1024)
+            .output(output.clone())
+            .build()
+    }
+
+    fn run_session(input: &str, plain_errors: bool) -> (String, Vec<Cell>) {
+        let output = OutputBuffer::new();
+        let mut forth = setup_interpreter(&output);
+        let result = repl(input.as_bytes(), &mut forth, plain_errors);
+
+        assert!(result.is_ok());
+        (output.contents(), forth.stack().to_vec())
+    }
+
+    #[test]
+    fn test_each_line_prints_ok() {
+        let (output, stack) = run_session("1 2\n+\n", false);
+
+        assert_eq!(output, " ok\n ok\n");
+        assert_eq!(stack, vec![3]);
+    }
+
+    #[test]
+    fn test_definitions_persist_across_lines() {
+        let (output, stack) = run_session(": SQUARE DUP * ;\n4 SQUARE\n", false);
+
+        assert_eq!(output, " ok\n ok\n");
+        assert_eq!(stack, vec![16]);
+    }
+
+    #[test]
+    fn test_error_resets_only_the_data_stack() {
+        let input = ": TWICE 2 * ;\n1 2 FOO\n5 TWICE\n";
+        let (output, stack) = run_session(input, false);
+
+        assert_eq!(output, " ok\n? at 1:5\n ok\n");
+        assert_eq!(stack, vec![10]);
+    }
+
+    #[test]
+    fn test_plain_errors_in_session() {
+        let (output, stack) = run_session("DROP\nSWPA\n7\n", true);
+
+        assert_eq!(output, "stack-underflow\n?\n ok\n");
+        assert_eq!(stack, vec![7]);
+    }
+
+    #[test]
+    fn test_program_output_precedes_ok() {
+        let (output, stack) = run_session("1 2 + .\n", false);
+
+        assert_eq!(output, "3 ok\n");
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn test_definition_continues_on_next_line() {
+        let (output, stack) = run_session(": SQ DUP\n* ;\n3 SQ\n", false);
+
+        assert_eq!(output, " compiled\n ok\n ok\n");
+        assert_eq!(stack, vec![9]);
+    }
+
+    #[test]
+    fn test_items_before_open_structure_run_once() {
+        let input = "1 2 + . .\" a\nb\" 0 10 0 DO I\n+ LOOP\n";
+        let (output, stack) = run_session(input, false);
+
+        assert_eq!(output, "3 compiled\na\nb compiled\n ok\n");
+        assert_eq!(stack, vec![45]);
+    }
+
+    #[test]
+    fn test_comment_continues_on_next_line() {
+        let (output, stack) = run_session("1 ( uno\ndos ) 2\n", false);
+
+        assert_eq!(output, " compiled\n ok\n");
+        assert_eq!(stack, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_error_discards_pending_input() {
+        let (output, stack) = run_session(": BAD 1\nFOO ;\n5\n", false);
+
+        assert_eq!(output, " compiled\n? at 2:1\n ok\n");
+        assert_eq!(stack, vec![5]);
+    }
+
+    #[test]
+    fn test_mismatched_control_does_not_wait_for_more_input() {
+        let input = ": FOO IF ;\nBEGIN 1 THEN\n1 2 + .\n: BAR 3 ;\nBAR\n";
+        let (output, stack) = run_session(input, true);
+
+        let expected = [
+            "Error: ';' antes de 'THEN' en la estructura de control",
+            "Error: 'THEN' antes de 'UNTIL' o 'REPEAT' en la estructura de control",
+            "3 ok",
+            " ok",
+            " ok\n",
+        ];
+        assert_eq!(output, expected.join("\n"));
+        assert_eq!(stack, vec![3]);
+    }
+
+    #[test]
+    fn test_empty_input_prints_nothing() {
+        assert_eq!(run_session("", false), (String::new(), Vec::new()));
+    }
+}
@@ -0,0 +1,119 @@
+use forth_interpreter::errors::ForthError;
+use forth_interpreter::return_stack::ReturnStack;
+use forth_interpreter::return_stack_operations::{ReturnStackOp, apply_return_stack_operation};
+use forth_interpreter::stack::Stack;
+
+#[cfg(test)]
+mod return_stack_operations_test {
+    use super::*;
+
+    fn setup_stacks() -> (Stack, ReturnStack) {
+        (Stack::new(128 * 1024), ReturnStack::new(1024))
+    }
+
+    #[test]
+    fn test_to_r_and_r_from() {
+        let (mut stack, mut return_stack) = setup_stacks();
+
+        assert!(stack.push(7).is_ok());
+
+        assert_eq!(
+            apply_return_stack_operation(&mut stack, &mut return_stack, ReturnStackOp::ToR),
+            Ok(())
+        );
+        assert_eq!(stack.pop(), None);
+        assert_eq!(return_stack.len(), 1);
+
+        assert_eq!(
+            apply_return_stack_operation(&mut stack, &mut return_stack, ReturnStackOp::RFrom),
+            Ok(())
+        );
+        assert_eq!(stack.pop(), Some(7));
+        assert!(return_stack.is_empty());
+    }
+
+    #[test]
+    fn test_r_fetch_keeps_value() {
+        let (mut stack, mut return_stack) = setup_stacks();
+
+        assert_eq!(return_stack.push(3), Ok(()));
+
+        assert_eq!(
+            apply_return_stack_operation(&mut stack, &mut return_stack, ReturnStackOp::RFetch),
+            Ok(())
+        );
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(return_stack.pop(), Ok(3));
+    }
+
+    #[test]
+    fn test_two_to_r_and_two_r_from_keep_order() {
+        let (mut stack, mut return_stack) = setup_stacks();
+
+        assert!(stack.push(1).is_ok());
+        assert!(stack.push(2).is_ok());
+
+        assert_eq!(
+            apply_return_stack_operation(&mut stack, &mut return_stack, ReturnStackOp::TwoToR),
+            Ok(())
+        );
+        assert_eq!(return_stack.peek(), Ok(2));
+
+        assert_eq!(
+            apply_return_stack_operation(&mut stack, &mut return_stack, ReturnStackOp::TwoRFrom),
+            Ok(())
+        );
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.pop(), Some(1));
+    }
+
+    #[test]
+    fn test_r_from_empty_return_stack_should_fail() {
+        let (mut stack, mut return_stack) = setup_stacks();
+
+        let result =
+            apply_return_stack_operation(&mut stack, &mut return_stack, ReturnStackOp::RFrom);
+        assert_eq!(result, Err(ForthError::ReturnStackUnderflow));
+
+        let result =
+            apply_return_stack_operation(&mut stack, &mut return_stack, ReturnStackOp::RFetch);
+        assert_eq!(result, Err(ForthError::ReturnStackUnderflow));
+    }
+
+    #[test]
+    fn test_two_r_from_with_one_element_should_fail() {
+        let (mut stack, mut return_stack) = setup_stacks();
+
+        assert_eq!(return_stack.push(1), Ok(()));
+
+        let result =
+            apply_return_stack_operation(&mut stack, &mut return_stack, ReturnStackOp::TwoRFrom);
+        assert_eq!(result, Err(ForthError::ReturnStackUnderflow));
+    }
+
+    #[test]
+    fn test_to_r_on_full_return_stack_should_fail() {
+        let mut stack = Stack::new(128 * 1024);
+        let mut return_stack = ReturnStack::new(2);
+
+        assert!(stack.push(1).is_ok());
+        assert!(stack.push(2).is_ok());
+
+        assert_eq!(
+            apply_return_stack_operation(&mut stack, &mut return_stack, ReturnStackOp::ToR),
+            Ok(())
+        );
+        let result =
+            apply_return_stack_operation(&mut stack, &mut return_stack, ReturnStackOp::ToR);
+        assert_eq!(result, Err(ForthError::ReturnStackOverflow));
+    }
+
+    #[test]
+    fn test_to_r_with_empty_stack_should_fail() {
+        let (mut stack, mut return_stack) = setup_stacks();
+
+        let result =
+            apply_return_stack_operation(&mut stack, &mut return_stack, ReturnStackOp::ToR);
+        assert_eq!(result, Err(ForthError::StackUnderflow));
+    }
+}
@@ -0,0 +1,100 @@
+use forth_interpreter::errors::ForthError;
+use forth_interpreter::interpreter_state::InterpreterState;
+use forth_interpreter::program::execute_operation;
+use forth_interpreter::stack::Stack;
+use forth_interpreter::tokenizer::{Location, Tokenizer};
+
+#[cfg(test)]
+mod source_locations_test {
+    use super::*;
+
+    fn setup_stack() -> Stack {
+        Stack::new(128 * 1024)
+    }
+
+    fn run(input: &str) -> Result<(), ForthError> {
+        let mut stack = setup_stack();
+        let mut state = InterpreterState::new(1024);
+        execute_operation(&mut stack, input.to_string(), &mut state)
+    }
+
+    fn error_message(input: &str) -> String {
+        match run(input) {
+            Ok(()) => String::new(),
+            Err(error) => error.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_tokenizer_tracks_lines_and_columns() {
+        let mut tokens = Tokenizer::new("1 2\n  DUP\n\n\tSWAP");
+
+        assert_eq!(tokens.next(), Some("1"));
+        assert_eq!(tokens.next(), Some("2"));
+        assert_eq!(tokens.source().location, Location { line: 1, column: 3 });
+        assert_eq!(tokens.next(), Some("DUP"));
+        assert_eq!(tokens.source().location, Location { line: 2, column: 3 });
+        assert_eq!(tokens.next(), Some("SWAP"));
+        assert_eq!(tokens.source().token, "SWAP");
+        assert_eq!(tokens.source().location, Location { line: 4, column: 2 });
+        assert_eq!(tokens.next(), None);
+    }
+
+    #[test]
+    fn test_top_level_error_reports_location() {
+        assert_eq!(
+            error_message("1 2 +\n  DROP DROP"),
+            "stack-underflow at 2:8"
+        );
+    }
+
+    #[test]
+    fn test_unknown_word_reports_location_and_token() {
+        let result = run("1\n2 FOO");
+
+        assert_eq!(error_message("1\n2 FOO"), "? at 2:3");
+        assert!(matches!(
+            result,
+            Err(ForthError::At(located)) if located.source.token == "FOO"
+        ));
+    }
+
+    #[test]
+    fn test_error_inside_word_reports_call_trace() {
+        let input = ": AVERAGE\n    + 2 / ;\n: REPORT AVERAGE . ;\n1 REPORT";
+        assert_eq!(
+            error_message(input),
+            "stack-underflow at 2:5 in AVERAGE <- REPORT"
+        );
+    }
+
+    #[test]
+    fn test_error_inside_loop_in_word_reports_trace() {
+        let input = ": DIVIDE 0 / ;\n: RUN 3 0 DO 1 DIVIDE LOOP ;\nRUN";
+        assert_eq!(
+            error_message(input),
+            "division-by-zero at 1:12 in DIVIDE <- RUN"
+        );
+    }
+
+    #[test]
+    fn test_error_in_definition_reports_location() {
+        assert_eq!(
+            error_message(": FOO\n  1 IF 2 ;"),
+            "Error: ';' antes de 'THEN' en la estructura de control at 2:10"
+        );
+    }
+
+    #[test]
+    fn test_root_returns_original_error() {
+        let result = run(": FOO DROP ;\nFOO");
+        assert_eq!(
+            result.as_ref().map_err(ForthError::root),
+            Err(&ForthError::StackUnderflow)
+        );
+        assert_eq!(
+            result.map_err(|error| error.in_file("foo.fth").to_string()),
+            Err("stack-underflow at foo.fth:1:7 in FOO".to_string())
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use forth_interpreter::cell::Cell;
+use forth_interpreter::errors::ForthError;
+use forth_interpreter::interpreter::Interpreter;
+use forth_interpreter::output::OutputBuffer;
+use forth_interpreter::tokenizer::Tokenizer;
+
+#[cfg(test)]
+mod string_operations_test {
+    use super::*;
+
+    fn run_output(input: &str) -> Result<String, ForthError> {
+        let output = OutputBuffer::new();
+        let mut forth = Interpreter::builder().output(output.clone()).build();
+        forth.eval(input).map_err(ForthError::into_root)?;
+        Ok(output.contents())
+    }
+
+    #[test]
+    fn test_parse_until_keeps_spaces() {
+        let mut tokens = Tokenizer::new(".\"  a    b \" DUP");
+
+        assert_eq!(tokens.next(), Some(".\""));
+        assert_eq!(tokens.parse_until('"', false), Some(" a    b "));
+        assert_eq!(tokens.next(), Some("DUP"));
+    }
+
+    #[test]
+    fn test_parse_until_with_escapes() {
+        let mut tokens = Tokenizer::new("S\\\" a\\\"b\" DUP");
+
+        assert_eq!(tokens.next(), Some("S\\\""));
+        assert_eq!(tokens.parse_until('"', true), Some("a\\\"b"));
+        assert_eq!(tokens.next(), Some("DUP"));
+    }
+
+    #[test]
+    fn test_print_string_preserves_spacing() {
+        assert_eq!(run_output(".\" a    b\""), Ok("a    b".to_string()));
+        assert_eq!(run_output(".\" \" 1 ."), Ok("1".to_string()));
+        assert_eq!(run_output(".\" a\"1 ."), Ok("a1".to_string()));
+        assert_eq!(
+            run_output(".\" a\"b\""),
+            Err(ForthError::UnknownWord("B\"".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_unterminated_string() {
+        assert_eq!(
+            run_output(".\" sin cierre"),
+            Err(ForthError::UnterminatedString)
+        );
+        assert_eq!(
+            run_output("S\" sin cierre"),
+            Err(ForthError::UnterminatedString)
+        );
+    }
+
+    #[test]
+    fn test_s_quote_pushes_address_and_length() {
+        let mut forth = Interpreter::builder().output(OutputBuffer::new()).build();
+
+        assert!(forth.eval("S\" hola\"").is_ok());
+        assert_eq!(forth.stack(), &[0, 4]);
+        assert!(forth.eval("CLEAR S\" hola\" DROP C@").is_ok());
+        assert_eq!(forth.stack(), &[Cell::from(b'h')]);
+    }
+
+    #[test]
+    fn test_type_prints_string() {
+        assert_eq!(run_output("S\" a  b \" TYPE"), Ok("a  b ".to_string()));
+        assert_eq!(
+            run_output(": GREET S\" hola\" TYPE ; GREET GREET"),
+            Ok("holahola".to_string())
+        );
+        assert_eq!(run_output("S\" \" TYPE"), Ok(String::new()));
+        assert_eq!(run_output("TYPE"), Err(ForthError::StackUnderflow));
+    }
+
+    #[test]
+    fn test_strings_in_definitions_are_stored_once() {
+        let mut forth = Interpreter::builder().output(OutputBuffer::new()).build();
+
+        assert!(forth.eval(": NAME S\" forth\" ; HERE").is_ok());
+        assert!(forth.eval("NAME 2DROP NAME 2DROP HERE").is_ok());
+        assert_eq!(forth.stack(), &[5, 5]);
+    }
+
+    #[test]
+    fn test_escaped_string() {
+        assert_eq!(
+            run_output("S\\\" a\\tb\\nc\\\"d\\\\\" TYPE"),
+            Ok("a\tb\nc\"d\\".to_string())
+        );
+        assert_eq!(run_output("S\\\" \\q\\m\" TYPE"), Ok("\"\r\n".to_string()));
+    }
+
+    #[test]
+    fn test_counted_string() {
+        let mut forth = Interpreter::builder().output(OutputBuffer::new()).build();
+
+        assert!(forth.eval("C\" abc\" DUP C@ SWAP COUNT").is_ok());
+        assert_eq!(forth.stack(), &[3, 1, 3]);
+        assert_eq!(
+            run_output("C\" forth\" COUNT TYPE"),
+            Ok("forth".to_string())
+        );
+    }
+
+    #[test]
+    fn test_type_outside_data_space() {
+        assert_eq!(
+            run_output("100 3 TYPE"),
+            Err(ForthError::InvalidMemoryAddress)
+        );
+    }
+}
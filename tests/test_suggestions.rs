@@ -0,0 +1,199 @@
+use forth_interpreter::arithmetic_operations::{DivisionMode, OverflowPolicy};
+use forth_interpreter::boolean_operations::LogicMode;
+use forth_interpreter::cell::CellWidth;
+use forth_interpreter::errors::ForthError;
+use forth_interpreter::interpreter_state::InterpreterState;
+use forth_interpreter::program::{Config, execute_operation, parse_config};
+use forth_interpreter::stack::Stack;
+use forth_interpreter::suggestions::{edit_distance, suggest};
+use forth_interpreter::word_definitions::WordMap;
+
+#[cfg(test)]
+mod suggestions_test {
+    use super::*;
+
+    fn setup_stack() -> Stack {
+        Stack::new(128 * 1024)
+    }
+
+    fn run(input: &str) -> Result<(), ForthError> {
+        let mut stack = setup_stack();
+        let mut state = InterpreterState::new(1024);
+        execute_operation(&mut stack, input.to_string(), &mut state)
+    }
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("DUP", "DUP"), 0);
+        assert_eq!(edit_distance("DUPP", "DUP"), 1);
+        assert_eq!(edit_distance("SWPA", "SWAP"), 1);
+        assert_eq!(edit_distance("OVRE", "OVER"), 1);
+        assert_eq!(edit_distance("", "ROT"), 3);
+        assert_eq!(edit_distance("CR", "EMIT"), 4);
+    }
+
+    #[test]
+    fn test_suggest_builtin_words() {
+        let word_map = WordMap::new();
+        assert_eq!(suggest("dupp", &word_map), vec!["DUP".to_string()]);
+        assert_eq!(suggest("SWPA", &word_map), vec!["SWAP".to_string()]);
+        assert!(suggest("XYZZY", &word_map).is_empty());
+        assert!(suggest("X", &word_map).is_empty());
+    }
+
+    #[test]
+    fn test_suggest_user_defined_words() {
+        let mut stack = setup_stack();
+        let mut state = InterpreterState::new(1024);
+        let result = execute_operation(&mut stack, ": AVERAGE + 2 / ;".to_string(), &mut state);
+
+        assert!(result.is_ok());
+        assert_eq!(
+            suggest("AVERGE", &state.word_map),
+            vec!["AVERAGE".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_unknown_word_error_carries_token_and_suggestions() {
+        let result = run("1 DUPP");
+
+        assert!(matches!(
+            result.as_ref().map_err(ForthError::root),
+            Err(ForthError::UnknownWord(word)) if word == "DUPP"
+        ));
+        assert_eq!(
+            result.map_err(|error| error.to_string()),
+            Err("? at 1:3 (did you mean DUP?)".to_string())
+        );
+    }
+
+    #[test]
+    fn test_known_words_are_not_suggested_for_themselves() {
+        let word_map = WordMap::new();
+        assert!(!suggest("THEN", &word_map).contains(&"THEN".to_string()));
+        assert!(!suggest("dup", &word_map).contains(&"DUP".to_string()));
+        assert_eq!(
+            run("THEN").map_err(|error| error.to_string()),
+            Err("Error: 'THEN' sin estructura de control abierta at 1:1".to_string())
+        );
+        assert_eq!(
+            run("VARIABLE V 1 TO V").map_err(|error| error.to_string()),
+            Err("Error: 'V' no es un VALUE at 1:17".to_string())
+        );
+    }
+
+    #[test]
+    fn test_plain_error_is_question_mark() {
+        let result = run(": FOO 1 SWPA ;");
+        assert_eq!(
+            result.map_err(|error| error.root().to_string()),
+            Err("?".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_config_with_plain_errors() {
+        let config = parse_config(&args(&[
+            "forth",
+            "--plain-errors",
+            "foo.fth",
+            "stack-size=64",
+        ]));
+        assert_eq!(
+            config,
+            Ok(Config {
+                filename: Some("foo.fth".to_string()),
+                stack_size: 64,
+                return_stack_size: 16 * 1024,
+                plain_errors: true,
+                repl: false,
+                overflow: OverflowPolicy::Wrap,
+                division: DivisionMode::Symmetric,
+                logic: LogicMode::Logical,
+                legacy_rot: false,
+                spaced_emit: false,
+                cell_width: CellWidth::Bits16,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_config_without_file_starts_repl() {
+        let config = parse_config(&args(&["forth", "--plain-errors"]));
+        assert_eq!(
+            config.map(|config| (config.filename, config.repl)),
+            Ok((None, true))
+        );
+    }
+
+    #[test]
+    fn test_parse_config_with_overflow_policy() {
+        let config = parse_config(&args(&["forth", "foo.fth", "overflow=saturate"]));
+        assert_eq!(
+            config.map(|config| config.overflow),
+            Ok(OverflowPolicy::Saturate)
+        );
+
+        let config = parse_config(&args(&["forth", "foo.fth", "overflow=clamp"]));
+        assert_eq!(
+            config,
+            Err(ForthError::InvalidArgument("clamp".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_config_with_cell_width() {
+        let config = parse_config(&args(&["forth", "foo.fth", "cell-width=32"]));
+        assert_eq!(
+            config.map(|config| config.cell_width),
+            Ok(CellWidth::Bits32)
+        );
+
+        let config = parse_config(&args(&["forth", "foo.fth", "cell-width=8"]));
+        assert_eq!(config, Err(ForthError::InvalidArgument("8".to_string())));
+    }
+
+    #[test]
+    fn test_parse_config_with_division_mode() {
+        let config = parse_config(&args(&["forth", "foo.fth", "division=floored"]));
+        assert_eq!(
+            config.map(|config| config.division),
+            Ok(DivisionMode::Floored)
+        );
+
+        let config = parse_config(&args(&["forth", "foo.fth", "division=round"]));
+        assert_eq!(
+            config,
+            Err(ForthError::InvalidArgument("round".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_config_with_logic_mode() {
+        let config = parse_config(&args(&["forth", "foo.fth", "logic=bitwise"]));
+        assert_eq!(config.map(|config| config.logic), Ok(LogicMode::Bitwise));
+    }
+
+    #[test]
+    fn test_parse_config_with_legacy_rot() {
+        let config = parse_config(&args(&["forth", "foo.fth", "--legacy-rot"]));
+        assert_eq!(config.map(|config| config.legacy_rot), Ok(true));
+
+        let config = parse_config(&args(&["forth", "foo.fth"]));
+        assert_eq!(config.map(|config| config.legacy_rot), Ok(false));
+    }
+
+    #[test]
+    fn test_parse_config_with_spaced_emit() {
+        let config = parse_config(&args(&["forth", "foo.fth", "--spaced-emit"]));
+        assert_eq!(config.map(|config| config.spaced_emit), Ok(true));
+
+        let config = parse_config(&args(&["forth", "foo.fth"]));
+        assert_eq!(config.map(|config| config.spaced_emit), Ok(false));
+    }
+}
@@ -0,0 +1,103 @@
+use forth_interpreter::cell::{Cell, CellWidth};
+use forth_interpreter::errors::ForthError;
+use forth_interpreter::input::{InputBuffer, InputSource};
+use forth_interpreter::interpreter::Interpreter;
+use forth_interpreter::output::OutputBuffer;
+
+#[cfg(test)]
+mod unicode_test {
+    use super::*;
+
+    fn run_output(input: &str) -> Result<Vec<u8>, ForthError> {
+        let output = OutputBuffer::new();
+        let mut forth = Interpreter::builder()
+            .cell_width(CellWidth::Bits32)
+            .output(output.clone())
+            .build();
+        forth.eval(input).map_err(ForthError::into_root)?;
+        Ok(output.bytes())
+    }
+
+    fn run_input(text: &str, program: &str) -> Result<Vec<Cell>, ForthError> {
+        let mut forth = Interpreter::builder()
+            .cell_width(CellWidth::Bits32)
+            .input(InputBuffer::from(text))
+            .output(OutputBuffer::new())
+            .build();
+        forth.eval(program).map_err(ForthError::into_root)?;
+        Ok(forth.stack().to_vec())
+    }
+
+    #[test]
+    fn test_emit_writes_raw_bytes() {
+        assert_eq!(run_output("72 EMIT 105 EMIT"), Ok(b"Hi".to_vec()));
+        assert_eq!(run_output("195 EMIT 169 EMIT"), Ok("é".as_bytes().to_vec()));
+        assert_eq!(run_output("321 EMIT"), Ok(vec![65]));
+    }
+
+    #[test]
+    fn test_spaced_emit() {
+        let output = OutputBuffer::new();
+        let mut forth = Interpreter::builder()
+            .spaced_emit(true)
+            .output(output.clone())
+            .build();
+
+        assert!(forth.eval("72 EMIT 105 EMIT").is_ok());
+        assert_eq!(output.contents(), "H i ");
+    }
+
+    #[test]
+    fn test_xemit_writes_utf8() {
+        assert_eq!(
+            run_output("241 XEMIT 8364 XEMIT 128512 XEMIT"),
+            Ok("ñ€😀".as_bytes().to_vec())
+        );
+        assert_eq!(
+            run_output("-1 XEMIT 55296 XEMIT"),
+            Ok("\u{FFFD}\u{FFFD}".as_bytes().to_vec())
+        );
+        assert_eq!(run_output("XEMIT"), Err(ForthError::StackUnderflow));
+    }
+
+    #[test]
+    fn test_xc_size() {
+        assert_eq!(
+            run_input("", "65 XC-SIZE 241 XC-SIZE 8364 XC-SIZE 128512 XC-SIZE"),
+            Ok(vec![1, 2, 3, 4])
+        );
+    }
+
+    #[test]
+    fn test_xkey_reads_code_points() {
+        assert_eq!(
+            run_input("año€", "XKEY XKEY XKEY XKEY XKEY"),
+            Ok(vec![97, 241, 111, 8364, -1])
+        );
+    }
+
+    #[test]
+    fn test_invalid_utf8_is_replaced() {
+        let mut input = InputSource::new(Box::new(&b"\xC3A\xFF"[..]));
+
+        assert_eq!(input.next_char(), Ok(Some(char::REPLACEMENT_CHARACTER)));
+        assert_eq!(input.next_char(), Ok(Some('A')));
+        assert_eq!(input.next_char(), Ok(Some(char::REPLACEMENT_CHARACTER)));
+        assert_eq!(input.next_char(), Ok(None));
+    }
+
+    #[test]
+    fn test_xkey_and_xemit_round_trip() {
+        let input = InputBuffer::from("¡Olé!");
+        let output = OutputBuffer::new();
+        let mut forth = Interpreter::builder()
+            .cell_width(CellWidth::Bits32)
+            .input(input)
+            .output(output.clone())
+            .build();
+        let program = ": COPY BEGIN XKEY DUP -1 <> WHILE XEMIT REPEAT DROP ; COPY";
+
+        assert!(forth.eval(program).is_ok());
+        assert_eq!(output.contents(), "¡Olé!");
+    }
+}
@@ -1,3 +1,8 @@
+use forth_interpreter::cell::CellWidth;
+use forth_interpreter::compiler::Instruction;
+use forth_interpreter::data_space::DataSpace;
+use forth_interpreter::errors::ForthError;
+use forth_interpreter::tokenizer::Tokenizer;
 use forth_interpreter::word_definitions::{WordMap, handle_word_definition};
 
 #[cfg(test)]
@@ -7,57 +12,146 @@ mod word_definition_tests {
     #[test]
     fn test_define_simple_word() {
         let mut word_map = WordMap::new();
+        let mut data_space = DataSpace::new(1024);
         let input = ": FOO 1 2 ;";
-        let mut tokens = input.split_whitespace();
+        let mut tokens = Tokenizer::new(input);
         tokens.next();
-        assert!(handle_word_definition(&mut tokens, &mut word_map).is_ok());
+        assert!(
+            handle_word_definition(
+                &mut tokens,
+                &mut word_map,
+                &mut data_space,
+                CellWidth::default()
+            )
+            .is_ok()
+        );
 
-        let expected = vec!["1", "2"];
+        let expected = [Instruction::Literal(1), Instruction::Literal(2)];
         assert_eq!(
-            word_map.get("FOO"),
-            Some(&expected.iter().map(|s| s.to_string()).collect())
+            word_map
+                .get("FOO")
+                .map(|definition| &definition.code.instructions[..]),
+            Some(&expected[..])
         );
     }
 
     #[test]
     fn test_define_with_existing_word() {
         let mut word_map = WordMap::new();
+        let mut data_space = DataSpace::new(1024);
 
         let input = ": FOO 1 2 ;";
-        let mut tokens = input.split_whitespace();
+        let mut tokens = Tokenizer::new(input);
         tokens.next();
-        assert!(handle_word_definition(&mut tokens, &mut word_map).is_ok());
+        assert!(
+            handle_word_definition(
+                &mut tokens,
+                &mut word_map,
+                &mut data_space,
+                CellWidth::default()
+            )
+            .is_ok()
+        );
 
         let input2 = ": BAR FOO 3 ;";
-        let mut tokens = input2.split_whitespace();
+        let mut tokens = Tokenizer::new(input2);
         tokens.next();
-        assert!(handle_word_definition(&mut tokens, &mut word_map).is_ok());
+        assert!(
+            handle_word_definition(
+                &mut tokens,
+                &mut word_map,
+                &mut data_space,
+                CellWidth::default()
+            )
+            .is_ok()
+        );
+
+        let foo = word_map.find("FOO");
+        assert!(foo.is_some());
+
+        let expected = foo.map(|xt| vec![Instruction::Call(xt), Instruction::Literal(3)]);
+        assert_eq!(
+            word_map
+                .get("BAR")
+                .map(|definition| definition.code.instructions.to_vec()),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_redefinition_keeps_previous_references() {
+        let mut word_map = WordMap::new();
+        let mut data_space = DataSpace::new(1024);
+
+        for input in [": FOO 1 ;", ": BAR FOO ;", ": FOO 2 ;"] {
+            let mut tokens = Tokenizer::new(input);
+            tokens.next();
+            assert!(
+                handle_word_definition(
+                    &mut tokens,
+                    &mut word_map,
+                    &mut data_space,
+                    CellWidth::default()
+                )
+                .is_ok()
+            );
+        }
 
-        let expected: Vec<String> = vec!["1", "2", "3"].iter().map(|s| s.to_string()).collect();
-        assert_eq!(word_map.get("BAR"), Some(&expected));
+        let old_foo = word_map
+            .get("BAR")
+            .map(|definition| definition.code.instructions.to_vec());
+        let new_foo = word_map.find("FOO").map(|xt| vec![Instruction::Call(xt)]);
+        assert_ne!(old_foo, new_foo);
     }
 
     #[test]
     fn test_redefine_number_should_fail() {
         let mut word_map = WordMap::new();
+        let mut data_space = DataSpace::new(1024);
         let input = ": 1 2 ;";
-        let mut tokens = input.split_whitespace();
+        let mut tokens = Tokenizer::new(input);
         tokens.next();
-        let result = handle_word_definition(&mut tokens, &mut word_map);
+        let result = handle_word_definition(
+            &mut tokens,
+            &mut word_map,
+            &mut data_space,
+            CellWidth::default(),
+        );
 
-        assert_eq!(result, Err("invalid-word".to_string()));
+        assert_eq!(result, Err(ForthError::InvalidWord));
+    }
+
+    #[test]
+    fn test_unknown_word_in_body_should_fail() {
+        let mut word_map = WordMap::new();
+        let mut data_space = DataSpace::new(1024);
+        let input = ": FOO BAZ ;";
+        let mut tokens = Tokenizer::new(input);
+        tokens.next();
+        let result = handle_word_definition(
+            &mut tokens,
+            &mut word_map,
+            &mut data_space,
+            CellWidth::default(),
+        );
+
+        assert_eq!(result, Err(ForthError::UnknownWord("BAZ".to_string())));
+        assert!(word_map.get("FOO").is_none());
     }
 
     #[test]
     fn test_missing_semicolon_should_fail() {
         let mut word_map = WordMap::new();
+        let mut data_space = DataSpace::new(1024);
         let input = "FOO 1 2";
-        let mut tokens = input.split_whitespace();
-        let result = handle_word_definition(&mut tokens, &mut word_map);
-
-        assert_eq!(
-            result,
-            Err("Error: Se esperaba ';' al final de la definición".to_string())
+        let mut tokens = Tokenizer::new(input);
+        let result = handle_word_definition(
+            &mut tokens,
+            &mut word_map,
+            &mut data_space,
+            CellWidth::default(),
         );
+
+        assert_eq!(result, Err(ForthError::UnterminatedDefinition));
     }
 }